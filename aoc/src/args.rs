@@ -0,0 +1,727 @@
+use std::path::{Path, PathBuf};
+
+use crate::player;
+use aoc_core::{
+    bench,
+    render::{Format, Palette},
+    Part,
+};
+
+pub const USAGE: &str = "usage:
+    aoc [-v|-vv] <command>...
+    aoc -h|--help
+    aoc list
+    aoc run --day <N> [--part <P>] [--input <PATH>] [--format text|json|ndjson]
+    aoc run --all [--part <P>] [--format text|json|ndjson]
+    aoc bench [--day <N>] [--warmup <N>] [--iterations <N>]
+              [--baseline <PATH>] [--save-baseline <PATH>]
+              [--size <N> [--seed <N>]]
+    aoc verify [--day <N>] [--record]
+    aoc fetch [--day <N>]
+    aoc submit --day <N> --part <P> [--answer <ANSWER>]
+    aoc generate --day <N> --size <N> [--seed <N>]
+    aoc animate --day <N> [--part <P>] [--fps <N>] [--limit <N>] [--input <PATH>]
+    aoc render --day <N> [--image <NAME>] [--format ppm|svg|png] [--scale <N>]
+               [--palette <RRGGBB,...>] [--input <PATH>] [--out <DIR>]
+    aoc watch --day <N> [--part <P>] [--input <PATH>]
+    aoc new <N> [--title <TITLE>]
+    aoc repl --day <N> [--input <PATH>]
+
+an input path of - reads the input from stdin
+bench reports allocations when built with --features count-allocs
+-v logs debug events of the solvers to stderr, -vv every event, and AOC_LOG
+takes extra filters such as day12=trace";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    Run(Run),
+    Bench(Bench),
+    Verify(Verify),
+    Fetch(Fetch),
+    Submit(Submit),
+    Generate(Generate),
+    Render(Render),
+    Animate(Animate),
+    Watch(Watch),
+    New(New),
+    Repl(Repl),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Days {
+    All,
+    One(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Run {
+    pub days: Days,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub output: Output,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    Text,
+    /// A single array once every day is done.
+    Json,
+    /// One object per line as soon as each day is done.
+    Ndjson,
+}
+
+impl std::str::FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::Ndjson),
+            _ => Err(format!("{s}, expected text, json or ndjson")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bench {
+    pub days: Days,
+    pub config: bench::Config,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    /// Bench on generated inputs of this size instead of the real ones.
+    pub size: Option<usize>,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Verify {
+    pub days: Days,
+    pub record: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fetch {
+    pub days: Days,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Submit {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Generate {
+    pub day: u32,
+    pub size: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Render {
+    pub day: u32,
+    /// Every image of the day when not set.
+    pub image: Option<String>,
+    pub format: Format,
+    pub scale: usize,
+    pub palette: Palette,
+    pub input: Option<PathBuf>,
+    pub out: PathBuf,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Animate {
+    pub day: u32,
+    pub part: Part,
+    pub options: player::Options,
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Watch {
+    pub day: u32,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct New {
+    pub day: u32,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Repl {
+    pub day: u32,
+    pub input: Option<PathBuf>,
+}
+
+impl Command {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            Some("-h" | "--help") => Ok(Self::Help),
+            Some("list") => match args.next() {
+                Some(arg) => Err(format!("unexpected argument: {arg}")),
+                None => Ok(Self::List),
+            },
+            Some("run") => Run::parse(args).map(Self::Run),
+            Some("bench") => Bench::parse(args).map(Self::Bench),
+            Some("verify") => Verify::parse(args).map(Self::Verify),
+            Some("fetch") => Fetch::parse(args).map(Self::Fetch),
+            Some("submit") => Submit::parse(args).map(Self::Submit),
+            Some("generate") => Generate::parse(args).map(Self::Generate),
+            Some("render") => Render::parse(args).map(Self::Render),
+            Some("animate") => Animate::parse(args).map(Self::Animate),
+            Some("watch") => Watch::parse(args).map(Self::Watch),
+            Some("new") => New::parse(args).map(Self::New),
+            Some("repl") => Repl::parse(args).map(Self::Repl),
+            Some(command) => Err(format!("unknown command: {command}")),
+            None => Err("missing command".to_string()),
+        }
+    }
+}
+
+impl Run {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut all = false;
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut output = Output::Text;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--format" => output = parse_value(&arg, args.next())?,
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--part" => part = Some(parse_part(&arg, args.next())?),
+                "--input" => input = Some(parse_path(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        let days = match (all, day) {
+            (true, None) => Days::All,
+            (false, Some(day)) => Days::One(day),
+            (true, Some(_)) => return Err("--all and --day are mutually exclusive".to_string()),
+            (false, None) => return Err("expected either --day or --all".to_string()),
+        };
+        if days == Days::All && input.is_some() {
+            return Err("--input can only be used with --day".to_string());
+        }
+        Ok(Self {
+            days,
+            part,
+            input,
+            output,
+        })
+    }
+}
+
+impl Bench {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut bench = Self {
+            days: Days::All,
+            config: bench::Config::default(),
+            baseline: None,
+            save_baseline: None,
+            size: None,
+            seed: 0,
+        };
+        let mut seed = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => bench.days = Days::One(parse_number(&arg, args.next())?),
+                "--warmup" => bench.config.warmup = parse_number(&arg, args.next())?,
+                "--iterations" => match parse_number(&arg, args.next())? {
+                    0 => return Err("--iterations has to be at least 1".to_string()),
+                    n => bench.config.iterations = n,
+                },
+                "--baseline" => bench.baseline = Some(parse_path(&arg, args.next())?),
+                "--save-baseline" => bench.save_baseline = Some(parse_path(&arg, args.next())?),
+                "--size" => bench.size = Some(parse_number(&arg, args.next())?),
+                "--seed" => seed = Some(parse_number(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        match (bench.size, seed) {
+            (None, Some(_)) => return Err("--seed can only be used with --size".to_string()),
+            (_, seed) => bench.seed = seed.unwrap_or(0),
+        }
+        Ok(bench)
+    }
+}
+
+impl Verify {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut verify = Self {
+            days: Days::All,
+            record: false,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => verify.days = Days::One(parse_number(&arg, args.next())?),
+                "--record" => verify.record = true,
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        Ok(verify)
+    }
+}
+
+impl Fetch {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut fetch = Self { days: Days::All };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => fetch.days = Days::One(parse_number(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        Ok(fetch)
+    }
+}
+
+impl Submit {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut answer = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--part" => part = Some(parse_part(&arg, args.next())?),
+                "--answer" => {
+                    answer = Some(
+                        args.next()
+                            .ok_or_else(|| format!("missing value for {arg}"))?,
+                    )
+                }
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        Ok(Self {
+            day: day.ok_or("submit requires --day")?,
+            part: part.ok_or("submit requires --part")?,
+            answer,
+        })
+    }
+}
+
+fn parse_part(flag: &str, value: Option<String>) -> Result<Part, String> {
+    Part::from_number(parse_number(flag, value)?)
+        .ok_or_else(|| format!("invalid value for {flag}, expected 1 or 2"))
+}
+
+impl Generate {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut size = None;
+        let mut seed = 0;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--size" => size = Some(parse_number(&arg, args.next())?),
+                "--seed" => seed = parse_number(&arg, args.next())?,
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        Ok(Self {
+            day: day.ok_or("generate requires --day")?,
+            size: size.ok_or("generate requires --size")?,
+            seed,
+        })
+    }
+}
+
+impl Render {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut render = Self {
+            day: 0,
+            image: None,
+            format: Format::Ppm,
+            scale: 4,
+            palette: Palette::default(),
+            input: None,
+            out: PathBuf::from("."),
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--image" => {
+                    render.image = Some(
+                        args.next()
+                            .ok_or_else(|| format!("missing value for {arg}"))?,
+                    )
+                }
+                "--format" => render.format = parse_value(&arg, args.next())?,
+                "--scale" => match parse_number(&arg, args.next())? {
+                    0 => return Err("--scale has to be at least 1".to_string()),
+                    n => render.scale = n,
+                },
+                "--palette" => render.palette = parse_value(&arg, args.next())?,
+                "--input" => render.input = Some(parse_path(&arg, args.next())?),
+                "--out" => render.out = parse_path(&arg, args.next())?,
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        render.day = day.ok_or("render requires --day")?;
+        Ok(render)
+    }
+}
+
+impl Animate {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut animate = Self {
+            day: 0,
+            part: Part::One,
+            options: player::Options::default(),
+            input: None,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--part" => animate.part = parse_part(&arg, args.next())?,
+                "--fps" => match parse_number(&arg, args.next())? {
+                    0 => return Err("--fps has to be at least 1".to_string()),
+                    n => animate.options.fps = n,
+                },
+                "--limit" => animate.options.limit = Some(parse_number(&arg, args.next())?),
+                "--input" => animate.input = Some(parse_path(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        animate.day = day.ok_or("animate requires --day")?;
+        Ok(animate)
+    }
+}
+
+impl Watch {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--part" => part = Some(parse_part(&arg, args.next())?),
+                "--input" => input = Some(parse_path(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        if input.as_deref() == Some(Path::new(aoc_core::input::STDIN)) {
+            return Err("watch can not read the input from stdin".to_string());
+        }
+        Ok(Self {
+            day: day.ok_or("watch requires --day")?,
+            part,
+            input,
+        })
+    }
+}
+
+impl New {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_number("new", args.next())?;
+        let mut title = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--title" => title = Some(args.next().ok_or("missing value for --title")?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        Ok(Self { day, title })
+    }
+}
+
+impl Repl {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut input = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number(&arg, args.next())?),
+                "--input" => input = Some(parse_path(&arg, args.next())?),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        if input.as_deref() == Some(Path::new(aoc_core::input::STDIN)) {
+            return Err("repl reads commands from stdin, not the input".to_string());
+        }
+        Ok(Self {
+            day: day.ok_or("repl requires --day")?,
+            input,
+        })
+    }
+}
+
+/// Takes the `-v` and `-vv` flags in front of the command, returning how
+/// verbose logging should be and the remaining arguments.
+pub fn verbosity(args: impl Iterator<Item = String>) -> (u8, impl Iterator<Item = String>) {
+    let mut args = args.peekable();
+    let mut verbosity = 0u8;
+    while let Some(flag) = args.next_if(|arg| arg == "-v" || arg == "-vv") {
+        verbosity = verbosity.saturating_add(flag.len() as u8 - 1);
+    }
+    (verbosity, args)
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<PathBuf, String> {
+    value
+        .map(PathBuf::from)
+        .ok_or_else(|| format!("missing value for {flag}"))
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: {value}"))
+}
+
+fn parse_value<T: std::str::FromStr<Err = String>>(
+    flag: &str,
+    value: Option<String>,
+) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|error| format!("invalid value for {flag}: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse("--help"), Ok(Command::Help));
+        assert_eq!(parse("-h"), Ok(Command::Help));
+        assert_eq!(parse("list"), Ok(Command::List));
+        assert_eq!(
+            parse("run --day 7 --part 2 --input path/to/file"),
+            Ok(Command::Run(Run {
+                days: Days::One(7),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("path/to/file")),
+                output: Output::Text,
+            }))
+        );
+        assert_eq!(
+            parse("run --all --format ndjson"),
+            Ok(Command::Run(Run {
+                days: Days::All,
+                part: None,
+                input: None,
+                output: Output::Ndjson,
+            }))
+        );
+    }
+
+    #[test]
+    fn verbosity() {
+        let verbose = |args: &str| {
+            let (verbosity, args) = super::verbosity(args.split_whitespace().map(str::to_string));
+            (verbosity, args.collect::<Vec<_>>().join(" "))
+        };
+        assert_eq!(verbose("list"), (0, "list".to_string()));
+        assert_eq!(verbose("-v run -v"), (1, "run -v".to_string()));
+        assert_eq!(verbose("-vv -v"), (3, String::new()));
+        assert_eq!(
+            parse("run --day 1 -v"),
+            Err("unexpected argument: -v".to_string())
+        );
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse("bench --day 11 --warmup 0 --iterations 3 --save-baseline base.txt"),
+            Ok(Command::Bench(Bench {
+                days: Days::One(11),
+                config: bench::Config {
+                    warmup: 0,
+                    iterations: 3,
+                },
+                baseline: None,
+                save_baseline: Some(PathBuf::from("base.txt")),
+                size: None,
+                seed: 0,
+            }))
+        );
+        assert_eq!(
+            parse("bench --size 1000000 --seed 3"),
+            Ok(Command::Bench(Bench {
+                days: Days::All,
+                config: bench::Config::default(),
+                baseline: None,
+                save_baseline: None,
+                size: Some(1000000),
+                seed: 3,
+            }))
+        );
+        assert!(parse("bench --seed 3").is_err());
+        assert!(parse("bench --iterations 0").is_err());
+        assert!(parse("bench --baseline").is_err());
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            parse("verify --day 3 --record"),
+            Ok(Command::Verify(Verify {
+                days: Days::One(3),
+                record: true,
+            }))
+        );
+    }
+
+    #[test]
+    fn fetch() {
+        assert_eq!(
+            parse("fetch"),
+            Ok(Command::Fetch(Fetch { days: Days::All }))
+        );
+        assert_eq!(
+            parse("fetch --day 12"),
+            Ok(Command::Fetch(Fetch {
+                days: Days::One(12)
+            }))
+        );
+        assert!(parse("fetch --part 1").is_err());
+    }
+
+    #[test]
+    fn submit() {
+        assert_eq!(
+            parse("submit --day 5 --part 2 --answer LBBVJBRMH"),
+            Ok(Command::Submit(Submit {
+                day: 5,
+                part: Part::Two,
+                answer: Some("LBBVJBRMH".to_string()),
+            }))
+        );
+        assert!(parse("submit --day 5").is_err());
+        assert!(parse("submit --part 1").is_err());
+        assert!(parse("submit --day 5 --part 1 --answer").is_err());
+    }
+
+    #[test]
+    fn generate() {
+        assert_eq!(
+            parse("generate --day 8 --size 2000 --seed 18446744073709551615"),
+            Ok(Command::Generate(Generate {
+                day: 8,
+                size: 2000,
+                seed: u64::MAX,
+            }))
+        );
+        assert!(parse("generate --day 8").is_err());
+        assert!(parse("generate --size 10").is_err());
+        assert!(parse("generate --day 8 --size -1").is_err());
+    }
+
+    #[test]
+    fn render() {
+        assert_eq!(
+            parse("render --day 8 --image visible --format svg --scale 10 --palette 000000,00ff00 --out images"),
+            Ok(Command::Render(Render {
+                day: 8,
+                image: Some("visible".to_string()),
+                format: Format::Svg,
+                scale: 10,
+                palette: "000000,00ff00".parse().unwrap(),
+                input: None,
+                out: PathBuf::from("images"),
+            }))
+        );
+        assert!(parse("render").is_err());
+        assert!(parse("render --day 8 --format gif").is_err());
+        assert!(parse("render --day 8 --scale 0").is_err());
+        assert!(parse("render --day 8 --palette red").is_err());
+    }
+
+    #[test]
+    fn animate() {
+        assert_eq!(
+            parse("animate --day 12 --part 2 --fps 60 --limit 100"),
+            Ok(Command::Animate(Animate {
+                day: 12,
+                part: Part::Two,
+                options: player::Options {
+                    fps: 60,
+                    limit: Some(100),
+                },
+                input: None,
+            }))
+        );
+        assert!(parse("animate --part 1").is_err());
+        assert!(parse("animate --day 5 --fps 0").is_err());
+    }
+
+    #[test]
+    fn watch() {
+        assert_eq!(
+            parse("watch --day 3 --part 1"),
+            Ok(Command::Watch(Watch {
+                day: 3,
+                part: Some(Part::One),
+                input: None,
+            }))
+        );
+        assert!(parse("watch").is_err());
+        assert!(parse("watch --day 3 --input -").is_err());
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Command::parse(
+                ["new", "13", "--title", "Distress Signal"]
+                    .map(str::to_string)
+                    .into_iter()
+            ),
+            Ok(Command::New(New {
+                day: 13,
+                title: Some("Distress Signal".to_string()),
+            }))
+        );
+        assert!(parse("new").is_err());
+        assert!(parse("new thirteen").is_err());
+        assert!(parse("new 13 --title").is_err());
+    }
+
+    #[test]
+    fn repl() {
+        assert_eq!(
+            parse("repl --day 7 --input vfs.txt"),
+            Ok(Command::Repl(Repl {
+                day: 7,
+                input: Some(PathBuf::from("vfs.txt")),
+            }))
+        );
+        assert!(parse("repl").is_err());
+        assert!(parse("repl --day 7 --input -").is_err());
+    }
+
+    #[test]
+    fn invalid_commands() {
+        assert!(parse("").is_err());
+        assert!(parse("list --all").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day seven").is_err());
+        assert!(parse("run --day 1 --part 3").is_err());
+        assert!(parse("run --all --day 1").is_err());
+        assert!(parse("run --all --input file").is_err());
+        assert!(parse("run --all --format yaml").is_err());
+    }
+}
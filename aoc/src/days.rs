@@ -0,0 +1,20 @@
+use aoc_core::DynSolution;
+
+pub const DAYS: [&dyn DynSolution; 12] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+];
+
+pub fn get(number: u32) -> Option<&'static dyn DynSolution> {
+    DAYS.into_iter().find(|day| day.day() == number)
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2022;
+pub const USER_AGENT: &str = concat!(
+    "github.com/ShadowCurse/advent_of_code_2022 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession(Option<PathBuf>),
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, std::io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSession(Some(path)) => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {}",
+                path.display()
+            ),
+            Self::MissingSession(None) => write!(f, "no session token, set AOC_SESSION"),
+            Self::Status(status, url) => write!(f, "{url}: server responded with {status}"),
+            Self::Transport(message) => write!(f, "{message}"),
+            Self::Io(path, error) => write!(f, "{}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ureq::Error> for Error {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                Self::Status(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(transport) => Self::Transport(transport.to_string()),
+        }
+    }
+}
+
+pub fn session_file() -> Option<PathBuf> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// Session token from `AOC_SESSION`, falling back to the config file.
+pub fn session() -> Result<String, Error> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let path = session_file();
+    match path.as_deref().map(std::fs::read_to_string) {
+        Some(Ok(session)) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(Error::MissingSession(path)),
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session()?))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call()?;
+        response
+            .into_string()
+            .map_err(|error| Error::Transport(format!("{url}: {error}")))
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(form)?;
+        response
+            .into_string()
+            .map_err(|error| Error::Transport(format!("{url}: {error}")))
+    }
+
+    pub fn input(&self, day: u32) -> Result<String, Error> {
+        self.get(&format!("/{YEAR}/day/{day}/input"))
+    }
+}
+
+/// Reads the input cached at `path`, downloading it only if it is not there yet.
+/// The input is normalized, the cached file is kept as downloaded.
+pub fn cached_input(
+    day: u32,
+    path: &Path,
+    client: impl FnOnce() -> Result<Client, Error>,
+) -> Result<String, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(input) => return Ok(aoc_core::input::normalize(&input).into_owned()),
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            return Err(Error::Io(path.to_path_buf(), error))
+        }
+        Err(_) => {}
+    }
+    let input = client()?.input(day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|error| Error::Io(dir.to_path_buf(), error))?;
+    }
+    std::fs::write(path, &input).map_err(|error| Error::Io(path.to_path_buf(), error))?;
+    Ok(aoc_core::input::normalize(&input).into_owned())
+}
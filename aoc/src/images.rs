@@ -0,0 +1,134 @@
+use aoc_core::{
+    render::{self, Color, Palette},
+    Grid, ParseError, Pos, Solution,
+};
+
+/// A picture of the puzzle state that can be drawn from the input of a day.
+pub struct Image {
+    pub day: u32,
+    pub name: &'static str,
+    draw: fn(&str, &Palette) -> Result<Grid<Color>, ParseError>,
+}
+
+impl Image {
+    pub fn draw(&self, input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+        (self.draw)(input, palette)
+    }
+}
+
+pub const IMAGES: [Image; 6] = [
+    Image {
+        day: 8,
+        name: "heights",
+        draw: forest_heights,
+    },
+    Image {
+        day: 8,
+        name: "visible",
+        draw: forest_visible,
+    },
+    Image {
+        day: 9,
+        name: "tail",
+        draw: rope_tail,
+    },
+    Image {
+        day: 9,
+        name: "long-tail",
+        draw: long_rope_tail,
+    },
+    Image {
+        day: 10,
+        name: "crt",
+        draw: crt,
+    },
+    Image {
+        day: 12,
+        name: "heights",
+        draw: hill_heights,
+    },
+];
+
+pub fn images(day: u32) -> impl Iterator<Item = &'static Image> {
+    IMAGES.iter().filter(move |image| image.day == day)
+}
+
+fn forest_heights(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    let forest = day8::Day8::parse(input)?;
+    Ok(render::levels(forest.trees(), palette, 10, |&h| h as usize))
+}
+
+fn forest_visible(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    let forest = day8::Day8::parse(input)?;
+    Ok(render::levels(&forest.visible(), palette, 2, |&v| {
+        v as usize
+    }))
+}
+
+fn rope_tail(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    tail(input, palette, 2)
+}
+
+fn long_rope_tail(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    tail(input, palette, 10)
+}
+
+/// Cells visited by the tail inside the smallest box around all of them.
+fn tail(input: &str, palette: &Palette, knots: usize) -> Result<Grid<Color>, ParseError> {
+    let motions = day9::Day9::parse(input)?;
+    let visited = day9::tail_visited(&motions, knots).unwrap_or_default();
+    let min_x = visited.iter().map(|pos| pos.x).min().unwrap_or(0);
+    let max_x = visited.iter().map(|pos| pos.x).max().unwrap_or(0);
+    let min_y = visited.iter().map(|pos| pos.y).min().unwrap_or(0);
+    let max_y = visited.iter().map(|pos| pos.y).max().unwrap_or(0);
+    let width = max_x.abs_diff(min_x) as usize + 1;
+    let height = max_y.abs_diff(min_y) as usize + 1;
+    let mut grid = Grid::new(width, height, false);
+    for pos in visited {
+        grid[Pos::new(
+            pos.x.abs_diff(min_x) as usize,
+            pos.y.abs_diff(min_y) as usize,
+        )] = true;
+    }
+    Ok(render::levels(&grid, palette, 2, |&v| v as usize))
+}
+
+fn crt(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    let instructions = day10::Day10::parse(input)?;
+    let screen = day10::draw(&instructions);
+    Ok(render::levels(screen.pixels(), palette, 2, |&lit| {
+        lit as usize
+    }))
+}
+
+fn hill_heights(input: &str, palette: &Palette) -> Result<Grid<Color>, ParseError> {
+    let map = day12::Day12::parse(input)?;
+    Ok(render::levels(map.heights(), palette, 26, |&h| {
+        (h - b'a') as usize
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn images_draw_generated_inputs() {
+        let palette = Palette::default();
+        for image in &IMAGES {
+            let day = crate::days::get(image.day).unwrap();
+            let input = day.generate(0, 20);
+            let pixels = image.draw(&input, &palette).unwrap();
+            assert!(pixels.width() > 0 && pixels.height() > 0);
+        }
+        let pixels = images(9)
+            .next()
+            .unwrap()
+            .draw("R 4\nU 2\n", &palette)
+            .unwrap();
+        assert_eq!((pixels.width(), pixels.height()), (5, 2));
+        assert_eq!(pixels[Pos::new(4, 0)], palette.color(1, 2));
+        assert_eq!(pixels[Pos::new(0, 0)], palette.color(0, 2));
+        assert_eq!(pixels[Pos::new(0, 1)], palette.color(1, 2));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod args;
+pub mod days;
+pub mod fetch;
+pub mod images;
+pub mod player;
+pub mod pool;
+pub mod repl;
+pub mod scaffold;
+pub mod submit;
+pub mod trace;
+pub mod watch;
@@ -0,0 +1,509 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufWriter, Write},
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc::{
+    args::{
+        self, Animate, Bench, Command, Days, Fetch, Generate, New, Output, Render, Repl, Run,
+        Submit, Verify, Watch, USAGE,
+    },
+    days::{self, DAYS},
+    fetch::{self, Client},
+    images, player, pool,
+    submit::{self, Log, Outcome},
+    trace,
+};
+use aoc_core::{
+    answers::{Answers, Verdict},
+    report::{self, Report},
+    Answer, DynSolution, Part,
+};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: aoc_core::alloc::Counting = aoc_core::alloc::Counting;
+
+enum Error {
+    Args(String),
+    UnknownDay(u32),
+    Mismatches(usize),
+    Failed(usize),
+    Repl(String),
+    Core(aoc_core::Error),
+    Fetch(fetch::Error),
+    Submit(submit::Error),
+}
+
+impl From<aoc_core::Error> for Error {
+    fn from(error: aoc_core::Error) -> Self {
+        Self::Core(error)
+    }
+}
+
+impl From<fetch::Error> for Error {
+    fn from(error: fetch::Error) -> Self {
+        Self::Fetch(error)
+    }
+}
+
+impl From<submit::Error> for Error {
+    fn from(error: submit::Error) -> Self {
+        Self::Submit(error)
+    }
+}
+
+impl From<aoc_core::ParseError> for Error {
+    fn from(error: aoc_core::ParseError) -> Self {
+        Self::Core(error.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Args(message) => write!(f, "{message}\n\n{USAGE}"),
+            Self::UnknownDay(day) => write!(f, "day {day} is not solved"),
+            Self::Mismatches(n) => write!(f, "{n} answer(s) do not match answers.toml"),
+            Self::Failed(n) => write!(f, "{n} day(s) failed"),
+            Self::Repl(error) => write!(f, "repl: {error}"),
+            Self::Core(error) => write!(f, "{error}"),
+            Self::Fetch(error) => write!(f, "{error}"),
+            Self::Submit(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let (verbosity, args) = args::verbosity(std::env::args().skip(1));
+    trace::init(verbosity);
+    let result = Command::parse(args).map_err(Error::Args).and_then(execute);
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            match error {
+                Error::Args(_) => ExitCode::from(2),
+                _ => ExitCode::FAILURE,
+            }
+        }
+    }
+}
+
+fn execute(command: Command) -> Result<(), Error> {
+    match command {
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("day {:>2}: {}", day.day(), day.title());
+            }
+            Ok(())
+        }
+        Command::Run(run) => execute_run(run),
+        Command::Bench(bench) => execute_bench(bench),
+        Command::Verify(verify) => execute_verify(verify),
+        Command::Fetch(fetch) => execute_fetch(fetch),
+        Command::Submit(submit) => execute_submit(submit),
+        Command::Generate(generate) => execute_generate(generate),
+        Command::Render(render) => execute_render(render),
+        Command::Animate(animate) => execute_animate(animate),
+        Command::Watch(watch) => execute_watch(watch),
+        Command::New(new) => execute_new(new),
+        Command::Repl(repl) => execute_repl(repl),
+    }
+}
+
+fn execute_run(run: Run) -> Result<(), Error> {
+    let parts = match run.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    if run.days == Days::All {
+        return execute_run_all(run.output, &parts);
+    }
+    let mut reports = Vec::new();
+    let mut failed = 0;
+    for day in select(run.days)? {
+        let input = match &run.input {
+            Some(path) => aoc_core::input::read_path(path)?,
+            None => read_input(day.day())?,
+        };
+        let day_reports = report::run(day, &input, &parts)?;
+        print_reports(run.output, day, &day_reports);
+        if day_reports
+            .iter()
+            .any(|report| matches!(report.answer, Answer::Unsolved(_)))
+        {
+            failed += 1;
+        }
+        reports.extend(day_reports);
+    }
+    if run.output == Output::Json {
+        println!("{}", report::to_json_array(&reports));
+    }
+    match failed {
+        0 => Ok(()),
+        n => Err(Error::Failed(n)),
+    }
+}
+
+/// Runs every day on a thread pool. Failing days are reported and skipped.
+/// NDJSON lines are printed as soon as each day is done, the other formats
+/// print the answers in order of days once all of them are done.
+fn execute_run_all(output: Output, parts: &[Part]) -> Result<(), Error> {
+    let job = |day: &&dyn DynSolution| {
+        let start = Instant::now();
+        let reports = read_input(day.day())
+            .and_then(|input| Ok(report::run(*day, &input, parts)?))
+            .map_err(|error| error.to_string());
+        (reports, start.elapsed())
+    };
+    let results = pool::map_with(&DAYS, pool::threads(), job, |i, result| {
+        if output != Output::Ndjson {
+            return;
+        }
+        match result {
+            Ok((Ok(reports), _)) => print_reports(output, DAYS[i], reports),
+            Ok((Err(error), _)) | Err(error) => {
+                eprintln!("error: day {}: {error}", DAYS[i].day())
+            }
+        }
+    });
+    let outcomes = DAYS
+        .iter()
+        .zip(results)
+        .map(|(day, result)| {
+            let (reports, elapsed) = match result {
+                Ok((reports, elapsed)) => (reports, Some(elapsed)),
+                Err(panic) => (Err(panic), None),
+            };
+            report::Outcome {
+                day: day.day(),
+                title: day.title(),
+                elapsed,
+                reports,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut reports = Vec::new();
+    let printed = match output {
+        Output::Ndjson => &[][..],
+        Output::Text | Output::Json => &outcomes[..],
+    };
+    for (day, outcome) in DAYS.iter().zip(printed) {
+        match &outcome.reports {
+            Ok(day_reports) => {
+                print_reports(output, *day, day_reports);
+                reports.extend(day_reports.iter().cloned());
+            }
+            Err(error) => eprintln!("error: day {}: {error}", day.day()),
+        }
+    }
+    match output {
+        Output::Text => print!("\n{}", report::summary(&outcomes)),
+        Output::Json => println!("{}", report::to_json_array(&reports)),
+        Output::Ndjson => {}
+    }
+    match outcomes
+        .iter()
+        .filter(|outcome| outcome.failure().is_some())
+        .count()
+    {
+        0 => Ok(()),
+        n => Err(Error::Failed(n)),
+    }
+}
+
+/// Prints the answers of a day as text or NDJSON, JSON is printed as a
+/// whole once every day is done.
+fn print_reports(output: Output, day: &dyn DynSolution, reports: &[Report]) {
+    match output {
+        Output::Text => {
+            println!("day {}: {}", day.day(), day.title());
+            for report in reports {
+                aoc_core::print_answer(report.part, &report.answer);
+            }
+        }
+        Output::Json => {}
+        Output::Ndjson => {
+            for report in reports {
+                println!("{}", report.to_json());
+            }
+        }
+    }
+}
+
+fn select(days: Days) -> Result<Vec<&'static dyn DynSolution>, Error> {
+    match days {
+        Days::All => Ok(DAYS.to_vec()),
+        Days::One(number) => Ok(vec![days::get(number).ok_or(Error::UnknownDay(number))?]),
+    }
+}
+
+fn execute_bench(bench: Bench) -> Result<(), Error> {
+    let days = select(bench.days)?;
+    let baseline = bench
+        .baseline
+        .as_deref()
+        .map(aoc_core::bench::Baseline::load)
+        .transpose()?;
+    let mut measurements = Vec::new();
+    for day in days {
+        let input = match bench.size {
+            Some(size) => day.generate(bench.seed, size),
+            None => read_input(day.day())?,
+        };
+        measurements.extend(aoc_core::bench::bench(day, &input, &bench.config)?);
+    }
+    print!(
+        "{}",
+        aoc_core::bench::report(&measurements, baseline.as_ref())
+    );
+    if let Some(path) = bench.save_baseline {
+        aoc_core::bench::Baseline::from_measurements(&measurements).save(&path)?;
+    }
+    Ok(())
+}
+
+fn execute_verify(verify: Verify) -> Result<(), Error> {
+    let path = aoc_core::answers::path();
+    let mut answers = if path.exists() {
+        Answers::load(&path)?
+    } else {
+        Answers::default()
+    };
+    let mut mismatches = 0;
+    let mut recorded = 0;
+    for day in select(verify.days)? {
+        let input = read_input(day.day())?;
+        for check in aoc_core::answers::verify(day, &input, &answers)? {
+            let name = format!("day {} part {}", check.day, check.part);
+            match check.verdict {
+                Verdict::Correct => println!("{name}: ok"),
+                Verdict::Wrong(expected) => {
+                    mismatches += 1;
+                    println!(
+                        "{name}: MISMATCH\n  expected: {expected}\n  got: {}",
+                        check.answer
+                    );
+                }
+                Verdict::Unknown if verify.record => {
+                    recorded += 1;
+                    answers.insert(check.day, check.part, &check.answer);
+                    println!("{name}: recorded {}", check.answer);
+                }
+                Verdict::Unknown => println!(
+                    "{name}: unknown, rerun with --record to save {}",
+                    check.answer
+                ),
+                Verdict::Unsolved(reason) => {
+                    mismatches += 1;
+                    println!("{name}: NO ANSWER, {reason}");
+                }
+            }
+        }
+    }
+    if recorded != 0 {
+        answers.save(&path)?;
+    }
+    match mismatches {
+        0 => Ok(()),
+        n => Err(Error::Mismatches(n)),
+    }
+}
+
+fn execute_fetch(fetch: Fetch) -> Result<(), Error> {
+    for day in select(fetch.days)? {
+        let path = aoc_core::input::path(day.day());
+        if path.exists() {
+            println!("day {}: cached at {}", day.day(), path.display());
+        } else {
+            fetch::cached_input(day.day(), &path, Client::from_env)?;
+            println!("day {}: saved to {}", day.day(), path.display());
+        }
+    }
+    Ok(())
+}
+
+fn execute_submit(args: Submit) -> Result<(), Error> {
+    let day = days::get(args.day).ok_or(Error::UnknownDay(args.day))?;
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let input = read_input(day.day())?;
+            let answer = day.parse(&input)?.solve(args.part);
+            match answer {
+                Answer::Bitmap(bitmap) => {
+                    return Err(Error::Args(format!(
+                        "the answer is a bitmap, pass the letters it shows with --answer\n{bitmap}"
+                    )))
+                }
+                Answer::Unsolved(reason) => {
+                    return Err(aoc_core::Error::Unsolved(day.day(), args.part, reason).into())
+                }
+                answer => answer.to_string(),
+            }
+        }
+    };
+    let path = submit::log_path();
+    let mut log = Log::load(&path)?;
+    let client = Client::from_env()?;
+    let name = format!("day {} part {}", day.day(), args.part);
+    let response = submit::submit(
+        &client,
+        &mut log,
+        day.day(),
+        args.part,
+        &answer,
+        submit::now(),
+    )?;
+    log.save(&path)?;
+    println!("{name}: {answer} is {}", response.outcome);
+    println!("{}", response.message);
+    if response.outcome == Outcome::Correct {
+        record_answer(day.day(), args.part, answer)?;
+    }
+    Ok(())
+}
+
+/// Adds an answer accepted by the site to answers.toml, unless one is already there.
+fn record_answer(day: u32, part: Part, answer: String) -> Result<(), Error> {
+    let path = aoc_core::answers::path();
+    let mut answers = if path.exists() {
+        Answers::load(&path)?
+    } else {
+        Answers::default()
+    };
+    if answers.get(day, part).is_none() {
+        answers.insert(day, part, &Answer::Str(answer));
+        answers.save(&path)?;
+    }
+    Ok(())
+}
+
+fn execute_generate(generate: Generate) -> Result<(), Error> {
+    let day = days::get(generate.day).ok_or(Error::UnknownDay(generate.day))?;
+    print!("{}", day.generate(generate.seed, generate.size));
+    Ok(())
+}
+
+fn execute_render(render: Render) -> Result<(), Error> {
+    let day = days::get(render.day).ok_or(Error::UnknownDay(render.day))?;
+    let images = images::images(day.day())
+        .filter(|image| {
+            render
+                .image
+                .as_deref()
+                .is_none_or(|name| name == image.name)
+        })
+        .collect::<Vec<_>>();
+    if images.is_empty() {
+        let names = images::images(day.day())
+            .map(|image| image.name)
+            .collect::<Vec<_>>();
+        return Err(Error::Args(match (render.image, names.is_empty()) {
+            (_, true) => format!("day {} has no images", day.day()),
+            (Some(name), false) => format!(
+                "day {} has no image {name}, expected one of {}",
+                day.day(),
+                names.join(", ")
+            ),
+            (None, false) => unreachable!("all images of a day were selected"),
+        }));
+    }
+    let input = match render.input {
+        Some(path) => aoc_core::input::read_path(&path)?,
+        None => read_input(day.day())?,
+    };
+    for image in images {
+        let pixels = image.draw(&input, &render.palette)?;
+        let path = render.out.join(format!(
+            "day{}-{}.{}",
+            day.day(),
+            image.name,
+            render.format.extension()
+        ));
+        let io_error = |error| aoc_core::Error::Io(path.clone(), error);
+        let mut file = BufWriter::new(File::create(&path).map_err(io_error)?);
+        aoc_core::render::write(&pixels, render.format, render.scale, &mut file)
+            .and_then(|()| file.flush())
+            .map_err(io_error)?;
+        println!(
+            "day {}: {} saved to {}",
+            day.day(),
+            image.name,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn execute_animate(animate: Animate) -> Result<(), Error> {
+    let day = days::get(animate.day).ok_or(Error::UnknownDay(animate.day))?;
+    let input = match animate.input {
+        Some(path) => aoc_core::input::read_path(&path)?,
+        None => read_input(day.day())?,
+    };
+    let mut simulation = player::simulation(day.day(), &input, animate.part)?
+        .ok_or_else(|| Error::Args(format!("day {} can not be animated", day.day())))?;
+    player::play(simulation.as_mut(), &animate.options)
+        .map_err(|error| aoc_core::Error::Io("stdout".into(), error))?;
+    Ok(())
+}
+
+fn execute_watch(watch: Watch) -> Result<(), Error> {
+    let day = days::get(watch.day).ok_or(Error::UnknownDay(watch.day))?;
+    // Cargo runs the day from the root of the workspace.
+    let input = match watch.input {
+        Some(path) => {
+            std::path::absolute(&path).map_err(|error| aoc_core::Error::Io(path, error))?
+        }
+        None => {
+            read_input(day.day())?;
+            aoc_core::input::path(day.day())
+        }
+    };
+    let mut stdout = std::io::stdout();
+    aoc::watch::watch(day.day(), watch.part, &input, &mut stdout)
+        .map_err(|error| aoc_core::Error::Io("stdout".into(), error).into())
+}
+
+fn execute_new(new: New) -> Result<(), Error> {
+    let title = new.title.unwrap_or_else(|| format!("Day {}", new.day));
+    let root = aoc_core::input::root();
+    let root = root.canonicalize().unwrap_or(root);
+    for path in aoc::scaffold::new_day(&root, new.day, &title)? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    Ok(())
+}
+
+fn execute_repl(repl: Repl) -> Result<(), Error> {
+    let day = days::get(repl.day).ok_or(Error::UnknownDay(repl.day))?;
+    let input = match repl.input {
+        Some(path) => aoc_core::input::read_path(&path)?,
+        None => read_input(day.day())?,
+    };
+    let session = aoc::repl::Session::new(day, &input)?;
+    let history = fetch::session_file().map(|path| path.with_file_name("repl_history"));
+    aoc::repl::run(session, history.as_deref()).map_err(|error| Error::Repl(error.to_string()))
+}
+
+/// Reads the default input of a day, downloading it on first use.
+fn read_input(day: u32) -> Result<String, Error> {
+    Ok(fetch::cached_input(
+        day,
+        &aoc_core::input::path(day),
+        Client::from_env,
+    )?)
+}
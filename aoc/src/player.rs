@@ -0,0 +1,279 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+use aoc_core::{ParseError, Part, Simulation, Solution};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+const MAX_FPS: u32 = 1000;
+const HELP: &str = "space pause, n step, +/- speed, q quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub fps: u32,
+    /// Stops after this many frames, the initial state being the first one.
+    pub limit: Option<usize>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fps: 10,
+            limit: None,
+        }
+    }
+}
+
+/// Step by step version of a day for the animation player, `None` for days
+/// that do not have one.
+pub fn simulation(
+    number: u32,
+    input: &str,
+    part: Part,
+) -> Result<Option<Box<dyn Simulation>>, ParseError> {
+    Ok(Some(match number {
+        5 => Box::new(day5::Crane::new(day5::Day5::parse(input)?, part)),
+        9 => {
+            let knots = match part {
+                Part::One => 2,
+                Part::Two => 10,
+            };
+            let motions = day9::Day9::parse(input)?;
+            Box::new(day9::Rope::new(motions, knots).expect("the rope has knots"))
+        }
+        10 => Box::new(day10::Screen::new(day10::Day10::parse(input)?)),
+        12 => Box::new(day12::Search::new(day12::Day12::parse(input)?, part)),
+        _ => return Ok(None),
+    }))
+}
+
+/// State changed by the keys, kept apart from the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Controls {
+    fps: u32,
+    paused: bool,
+    /// Single steps requested while paused.
+    steps: usize,
+    quit: bool,
+}
+
+impl Controls {
+    fn new(fps: u32) -> Self {
+        Self {
+            fps: fps.clamp(1, MAX_FPS),
+            paused: false,
+            steps: 0,
+            quit: false,
+        }
+    }
+
+    fn key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('n') | KeyCode::Right => {
+                self.paused = true;
+                self.steps += 1;
+            }
+            KeyCode::Char('+' | '=') | KeyCode::Up => self.fps = (self.fps * 2).min(MAX_FPS),
+            KeyCode::Char('-') | KeyCode::Down => self.fps = (self.fps / 2).max(1),
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            _ => {}
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    /// Whether the next frame should be shown now.
+    fn advance(&mut self, now: Instant, due: Instant) -> bool {
+        match self.paused {
+            true if self.steps != 0 => {
+                self.steps -= 1;
+                true
+            }
+            true => false,
+            false => due <= now,
+        }
+    }
+}
+
+/// Shows every step of `simulation` in place. Without a terminal the frames
+/// are printed one after another instead, as fast as they come.
+pub fn play(simulation: &mut dyn Simulation, options: &Options) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    if !(io::stdin().is_terminal() && stdout.is_terminal()) {
+        return print_frames(simulation, options.limit, &mut stdout);
+    }
+    let _raw_mode = RawMode::enable(&mut stdout)?;
+    let mut controls = Controls::new(options.fps);
+    let mut frames = 1;
+    // Clear the screen once, every frame then overwrites the previous one.
+    write!(stdout, "\x1b[2J")?;
+    draw(&mut stdout, simulation, &controls, frames)?;
+    let mut due = Instant::now() + controls.delay();
+    while options.limit.is_none_or(|limit| frames < limit) {
+        let timeout = match controls.paused {
+            true => Duration::from_millis(100),
+            false => due.saturating_duration_since(Instant::now()),
+        };
+        let mut changed = false;
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    controls.key(key);
+                    changed = true;
+                }
+            }
+        }
+        if controls.quit {
+            break;
+        }
+        if controls.advance(Instant::now(), due) {
+            if !simulation.step() {
+                break;
+            }
+            frames += 1;
+            due = Instant::now() + controls.delay();
+            changed = true;
+        }
+        if changed {
+            draw(&mut stdout, simulation, &controls, frames)?;
+        }
+    }
+    Ok(())
+}
+
+fn draw(
+    out: &mut impl Write,
+    simulation: &dyn Simulation,
+    controls: &Controls,
+    frames: usize,
+) -> io::Result<()> {
+    // Raw mode does not turn \n into \r\n, and every line clears what is
+    // left of the previous frame after it.
+    write!(out, "\x1b[H")?;
+    for line in simulation.to_string().lines() {
+        write!(out, "{line}\x1b[K\r\n")?;
+    }
+    let paused = if controls.paused { ", paused" } else { "" };
+    write!(
+        out,
+        "\x1b[K\r\nframe {frames}, {} fps{paused} | {HELP}\x1b[K\x1b[J",
+        controls.fps
+    )?;
+    out.flush()
+}
+
+fn print_frames(
+    simulation: &mut dyn Simulation,
+    limit: Option<usize>,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut frames = 1;
+    writeln!(out, "{simulation}")?;
+    while limit.is_none_or(|limit| frames < limit) && simulation.step() {
+        frames += 1;
+        writeln!(out, "\n{simulation}")?;
+    }
+    Ok(())
+}
+
+/// Restores the terminal even if the simulation panics.
+struct RawMode;
+
+impl RawMode {
+    fn enable(out: &mut impl Write) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        write!(out, "\x1b[?25l")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\r\n");
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+
+    struct Counter(u32);
+
+    impl Display for Counter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "count {}", self.0)
+        }
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 < 5
+        }
+    }
+
+    #[test]
+    fn frames() {
+        let mut out = Vec::new();
+        print_frames(&mut Counter(0), Some(3), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "count 0\n\ncount 1\n\ncount 2\n"
+        );
+
+        let mut out = Vec::new();
+        print_frames(&mut Counter(0), None, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap().lines().last(),
+            Some("count 4")
+        );
+
+        let mut out = Vec::new();
+        let controls = Controls::new(10);
+        draw(&mut out, &Counter(7), &controls, 8).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\x1b[Hcount 7\x1b[K\r\n"));
+        assert!(out.contains("frame 8, 10 fps |"));
+    }
+
+    #[test]
+    fn controls() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let mut controls = Controls::new(10);
+        let now = Instant::now();
+        assert!(controls.advance(now, now));
+        assert!(!controls.advance(now, now + Duration::from_secs(1)));
+
+        controls.key(key('+'));
+        assert_eq!(controls.delay(), Duration::from_millis(50));
+        for _ in 0..5 {
+            controls.key(key('-'));
+        }
+        assert_eq!(controls.fps, 1);
+
+        controls.key(key(' '));
+        assert!(controls.paused && !controls.advance(now, now));
+        controls.key(key('n'));
+        controls.key(key('n'));
+        assert!(controls.advance(now, now) && controls.advance(now, now));
+        assert!(!controls.advance(now, now));
+        controls.key(key(' '));
+        assert!(!controls.paused);
+
+        assert!(!controls.quit);
+        controls.key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(controls.quit);
+    }
+}
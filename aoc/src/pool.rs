@@ -0,0 +1,109 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use aoc_core::check::panic_message;
+
+/// Number of threads to use, one per available core.
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Runs `job` on every item on up to `threads` threads. Each thread picks the
+/// next item as soon as it is done with the previous one, so a slow item only
+/// holds up its own thread. Results come back in the order of `items`, a
+/// panicking job turning into an `Err` with the panic message.
+pub fn map<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    job: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>> {
+    map_with(items, threads, job, |_, _| {})
+}
+
+/// Like [`map`], also handing each result with the index of its item to
+/// `done` on the calling thread as soon as it is ready.
+pub fn map_with<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    job: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(usize, &Result<R, String>),
+) -> Vec<Result<R, String>> {
+    let next = AtomicUsize::new(0);
+    let mut results = (0..items.len()).map(|_| None).collect::<Vec<_>>();
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            let (next, job, sender) = (&next, &job, sender.clone());
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| job(item)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                if sender.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        // Only the threads hold senders now, so this ends with the last one.
+        drop(sender);
+        for (i, result) in receiver {
+            done(i, &result);
+            results[i] = Some(result);
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.expect("every item is picked by a thread"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_keeps_order_and_catches_panics() {
+        let items = (0..20).collect::<Vec<u32>>();
+        let results = map(&items, 4, |&n| {
+            assert!(n != 13, "unlucky");
+            thread::sleep(std::time::Duration::from_millis((20 - n as u64) % 7));
+            n * 2
+        });
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[3], Ok(6));
+        assert_eq!(results[19], Ok(38));
+        assert_eq!(results[13], Err("panicked: unlucky".to_string()));
+        assert!(map(&[] as &[u32], 4, |&n| n).is_empty());
+    }
+
+    #[test]
+    fn map_with_reports_each_result() {
+        let items = (0..10).collect::<Vec<u32>>();
+        let mut done = Vec::new();
+        let results = map_with(
+            &items,
+            3,
+            |&n| {
+                assert!(n != 4, "four");
+                n + 1
+            },
+            |i, result| done.push((i, result.clone())),
+        );
+        done.sort_by_key(|&(i, _)| i);
+        assert_eq!(
+            done.into_iter()
+                .map(|(_, result)| result)
+                .collect::<Vec<_>>(),
+            results
+        );
+        assert_eq!(results[4], Err("panicked: four".to_string()));
+    }
+}
@@ -0,0 +1,449 @@
+use std::path::Path;
+
+use aoc_core::{DynSolution, ParseError, Parsed, Part, Pos, Simulation, Solution};
+use rustyline::{
+    completion::Completer, error::ReadlineError, highlight::Highlighter, hint::Hinter,
+    history::DefaultHistory, validate::Validator, Context, Editor, Helper,
+};
+
+pub struct Command {
+    pub name: &'static str,
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+const COMMON: [Command; 3] = [
+    Command {
+        name: "help",
+        args: "",
+        help: "list the commands",
+    },
+    Command {
+        name: "solve",
+        args: "[PART]",
+        help: "answer of one or both parts",
+    },
+    Command {
+        name: "quit",
+        args: "",
+        help: "leave, as does ctrl-d",
+    },
+];
+
+/// Commands of a single day on top of the common ones.
+trait Explore {
+    fn commands(&self) -> &'static [Command];
+
+    /// Runs one of `commands`, returning what to print.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// A day with its input parsed once, answering commands about it.
+pub struct Session<'a> {
+    day: &'static dyn DynSolution,
+    parsed: Box<dyn Parsed + 'a>,
+    explorer: Option<Box<dyn Explore + 'a>>,
+}
+
+impl<'a> Session<'a> {
+    pub fn new(day: &'static dyn DynSolution, input: &'a str) -> Result<Self, ParseError> {
+        let explorer: Option<Box<dyn Explore>> = match day.day() {
+            7 => Some(Box::new(Vfs {
+                vfs: <day7::Day7 as Solution>::parse(input)?,
+                cwd: String::new(),
+            })),
+            8 => Some(Box::new(Forest(<day8::Day8 as Solution>::parse(input)?))),
+            10 => Some(Box::new(Cpu {
+                instructions: <day10::Day10 as Solution>::parse(input)?,
+                screen: None,
+            })),
+            12 => Some(Box::new(Map(<day12::Day12 as Solution>::parse(input)?))),
+            _ => None,
+        };
+        Ok(Self {
+            day,
+            parsed: day.parse(input)?,
+            explorer,
+        })
+    }
+
+    pub fn commands(&self) -> impl Iterator<Item = &Command> {
+        let own = self.explorer.as_ref().map_or(&[][..], |e| e.commands());
+        COMMON.iter().chain(own)
+    }
+
+    /// Runs a command line, returning what to print.
+    pub fn eval(&mut self, line: &str) -> Result<String, String> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(String::new());
+        };
+        let args = words.collect::<Vec<_>>();
+        match command {
+            "help" => Ok(self
+                .commands()
+                .map(|c| format!("{:<20} {}", format!("{} {}", c.name, c.args), c.help))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            "solve" => {
+                let parts = match args.as_slice() {
+                    [] => Part::ALL.to_vec(),
+                    [part] => vec![part
+                        .parse()
+                        .ok()
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| format!("no part {part}"))?],
+                    _ => return Err("usage: solve [PART]".to_string()),
+                };
+                Ok(parts
+                    .into_iter()
+                    .map(|part| match self.parsed.solve(part) {
+                        aoc_core::Answer::Bitmap(bitmap) => format!("part {part}:\n{bitmap}"),
+                        answer => format!("part {part}: {answer}"),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            command => match &mut self.explorer {
+                Some(explorer) if explorer.commands().iter().any(|c| c.name == command) => {
+                    explorer.run(command, &args)
+                }
+                _ => Err(format!(
+                    "unknown command {command} for day {}, try help",
+                    self.day.day()
+                )),
+            },
+        }
+    }
+
+    /// Command names starting with the word under the cursor, only for the
+    /// first word of the line.
+    fn complete(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let line = &line[..pos];
+        if line.trim_start().contains(' ') {
+            return (pos, Vec::new());
+        }
+        let start = line.len() - line.trim_start().len();
+        let names = self
+            .commands()
+            .filter(|c| c.name.starts_with(&line[start..]))
+            .map(|c| c.name.to_string())
+            .collect();
+        (start, names)
+    }
+}
+
+fn position(args: &[&str]) -> Result<Pos<usize>, String> {
+    match args {
+        [x, y] => match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => Ok(Pos::new(x, y)),
+            _ => Err(format!("invalid position {x} {y}")),
+        },
+        _ => Err("expected a position X Y".to_string()),
+    }
+}
+
+struct Vfs<'a> {
+    vfs: day7::VFS<'a>,
+    /// `/` separated, empty at the root.
+    cwd: String,
+}
+
+impl Vfs<'_> {
+    fn path(&self, args: &[&str]) -> Result<String, String> {
+        match args {
+            [] => Ok(self.cwd.clone()),
+            [path] if path.starts_with('/') => Ok(path.to_string()),
+            [path] => Ok(format!("{}/{path}", self.cwd)),
+            _ => Err("expected at most one path".to_string()),
+        }
+    }
+}
+
+impl Explore for Vfs<'_> {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "ls",
+                args: "[PATH]",
+                help: "content of a directory with sizes",
+            },
+            Command {
+                name: "cd",
+                args: "PATH",
+                help: "change the current directory",
+            },
+            Command {
+                name: "du",
+                args: "[PATH]",
+                help: "total size of a directory",
+            },
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let path = self.path(args)?;
+        let missing = || format!("no directory {path}");
+        match command {
+            "ls" => {
+                let entries = self.vfs.list(&path).ok_or_else(missing)?;
+                Ok(entries
+                    .iter()
+                    .map(|entry| match entry.is_dir {
+                        true => format!("{:>10} {}/", entry.size, entry.name),
+                        false => format!("{:>10} {}", entry.size, entry.name),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            "cd" => {
+                self.vfs.dir(&path).ok_or_else(missing)?;
+                // Resolve `..` so the path does not keep growing.
+                let mut names = Vec::new();
+                for name in path.split('/').filter(|name| !name.is_empty()) {
+                    match name {
+                        ".." => drop(names.pop()),
+                        name => names.push(name),
+                    }
+                }
+                self.cwd = names.iter().map(|name| format!("/{name}")).collect();
+                Ok(format!("/{}", self.cwd.trim_start_matches('/')))
+            }
+            _ => Ok(self.vfs.dir(&path).ok_or_else(missing)?.size.to_string()),
+        }
+    }
+}
+
+struct Forest(day8::Forest);
+
+impl Explore for Forest {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "tree",
+                args: "X Y",
+                help: "height, visibility and scenic score of a tree",
+            },
+            Command {
+                name: "scenic",
+                args: "X Y",
+                help: "scenic score of a tree",
+            },
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let pos = position(args)?;
+        let score = self
+            .0
+            .scenic_score(pos)
+            .ok_or_else(|| format!("no tree at {} {}", pos.x, pos.y))?;
+        match command {
+            "scenic" => Ok(score.to_string()),
+            _ => Ok(format!(
+                "height {}, {}, scenic score {score}",
+                self.0.trees()[pos],
+                match self.0.visible()[pos] {
+                    true => "visible",
+                    false => "hidden",
+                }
+            )),
+        }
+    }
+}
+
+struct Cpu {
+    instructions: Vec<day10::Instruction>,
+    screen: Option<day10::Screen>,
+}
+
+impl Explore for Cpu {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "step",
+                args: "[N]",
+                help: "run N cycles, 1 by default",
+            },
+            Command {
+                name: "reset",
+                args: "",
+                help: "go back to cycle 0",
+            },
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let instructions = &self.instructions;
+        let screen = self
+            .screen
+            .get_or_insert_with(|| day10::Screen::new(instructions.clone()));
+        if command == "reset" {
+            *screen = day10::Screen::new(instructions.clone());
+            return Ok(screen.to_string());
+        }
+        let cycles = match args {
+            [] => 1,
+            [n] => n.parse().map_err(|_| format!("invalid number {n}"))?,
+            _ => return Err("usage: step [N]".to_string()),
+        };
+        for _ in 0..cycles {
+            if !screen.step() {
+                return Ok(format!("{screen}\nprogram finished"));
+            }
+        }
+        Ok(screen.to_string())
+    }
+}
+
+struct Map(day12::HightMap);
+
+impl Explore for Map {
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            name: "path",
+            args: "[X Y]",
+            help: "fewest steps to E, from S by default",
+        }]
+    }
+
+    fn run(&mut self, _: &str, args: &[&str]) -> Result<String, String> {
+        let start = match args {
+            [] => self.0.start(),
+            args => position(args)?,
+        };
+        let height = self
+            .0
+            .heights()
+            .get(start)
+            .ok_or_else(|| format!("{} {} is outside of the map", start.x, start.y))?;
+        Ok(match self.0.shortest_path(start) {
+            Some(steps) => format!("{steps} steps from height {}", *height as char),
+            None => "E can not be reached".to_string(),
+        })
+    }
+}
+
+struct CommandHelper<'s, 'a>(&'s std::cell::RefCell<Session<'a>>);
+
+impl Completer for CommandHelper<'_, '_> {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(self.0.borrow().complete(line, pos))
+    }
+}
+
+impl Hinter for CommandHelper<'_, '_> {
+    type Hint = String;
+}
+
+impl Highlighter for CommandHelper<'_, '_> {}
+
+impl Validator for CommandHelper<'_, '_> {}
+
+impl Helper for CommandHelper<'_, '_> {}
+
+/// Reads commands until quit or end of input, keeping the history in
+/// `history` when given.
+pub fn run(session: Session, history: Option<&Path>) -> Result<(), ReadlineError> {
+    let prompt = format!("day{}> ", session.day.day());
+    let session = std::cell::RefCell::new(session);
+    let mut editor = Editor::<CommandHelper, DefaultHistory>::new()?;
+    editor.set_helper(Some(CommandHelper(&session)));
+    if let Some(history) = history {
+        // There is no history yet on the first run.
+        let _ = editor.load_history(history);
+    }
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => return Err(error),
+        };
+        editor.add_history_entry(line.as_str())?;
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+        match session.borrow_mut().eval(&line) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{output}"),
+            Err(error) => println!("error: {error}"),
+        }
+    }
+    if let Some(history) = history {
+        if let Some(dir) = history.parent() {
+            std::fs::create_dir_all(dir).map_err(ReadlineError::Io)?;
+        }
+        editor.save_history(history)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(day: u32, input: &str) -> Session<'_> {
+        Session::new(crate::days::get(day).unwrap(), input).unwrap()
+    }
+
+    #[test]
+    fn common_commands() {
+        let mut session = session(1, "1\n2\n\n4\n");
+        assert_eq!(session.eval("solve 1"), Ok("part 1: 4".to_string()));
+        assert_eq!(
+            session.eval("  solve "),
+            Ok("part 1: 4\npart 2: 7".to_string())
+        );
+        assert!(session.eval("solve 3").is_err());
+        assert!(session.eval("ls").is_err());
+        assert_eq!(session.eval(""), Ok(String::new()));
+        assert!(session.eval("help").unwrap().contains("solve [PART]"));
+        assert_eq!(session.complete("so", 2), (0, vec!["solve".to_string()]));
+        assert_eq!(session.complete("solve 1", 7), (7, vec![]));
+    }
+
+    #[test]
+    fn day_commands() {
+        let input = "$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\n";
+        let mut vfs = session(7, input);
+        assert_eq!(
+            vfs.eval("ls"),
+            Ok("         5 a/\n        10 b".to_string())
+        );
+        assert_eq!(vfs.eval("cd a"), Ok("/a".to_string()));
+        assert_eq!(vfs.eval("ls"), Ok("         5 c".to_string()));
+        assert_eq!(vfs.eval("du /"), Ok("15".to_string()));
+        assert_eq!(vfs.eval("cd .."), Ok("/".to_string()));
+        assert!(vfs.eval("cd b").is_err());
+
+        let mut forest = session(8, "303\n255\n653\n");
+        assert_eq!(forest.eval("scenic 1 1"), Ok("1".to_string()));
+        assert_eq!(
+            forest.eval("tree 0 0"),
+            Ok("height 3, visible, scenic score 0".to_string())
+        );
+        assert!(forest.eval("tree 3 0").is_err());
+
+        let mut cpu = session(10, "noop\naddx 3\n");
+        assert!(cpu.eval("step 2").unwrap().starts_with("cycle 2, x = 1"));
+        assert!(cpu.eval("step 5").unwrap().ends_with("program finished"));
+        assert!(cpu.eval("reset").unwrap().starts_with("cycle 0, x = 1"));
+
+        let mut map = session(12, "SbcdefghijklmnopqrstuvwxyE\n");
+        assert_eq!(map.eval("path"), Ok("25 steps from height a".to_string()));
+        assert_eq!(
+            map.eval("path 5 0"),
+            Ok("20 steps from height f".to_string())
+        );
+        assert!(map.eval("path 0 1").is_err());
+        assert_eq!(map.complete("pa", 2), (0, vec!["path".to_string()]));
+    }
+}
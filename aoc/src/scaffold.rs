@@ -0,0 +1,251 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::Error;
+
+const MANIFEST: &str = "\
+[package]
+name = \"dayN\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+aoc_core.workspace = true
+";
+
+const MAIN: &str = "\
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_core::run::<dayN::DayN>()
+}
+";
+
+const LIB: &str = r#"use std::fmt::Write;
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = N;
+
+pub struct DayN;
+
+impl Solution for DayN {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "TITLE";
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Answer {
+        lines.len().into()
+    }
+
+    fn part_2(lines: &Self::Input<'_>) -> Answer {
+        lines.len().into()
+    }
+}
+
+impl Generate for DayN {
+    /// `size` lines of numbers.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            writeln!(input, "{}", rng.range(0..=100)).unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1
+2
+3
+";
+
+    #[test]
+    #[ignore = "replace the example and answers with the ones from the puzzle"]
+    fn example() {
+        let lines = DayN::parse(EXAMPLE).unwrap();
+        assert_eq!(DayN::part_1(&lines), Answer::Int(0));
+        assert_eq!(DayN::part_2(&lines), Answer::Int(0));
+    }
+}
+"#;
+
+/// Creates the crate of a new day and registers it in the workspace and the
+/// runner. Nothing is written if the day already exists or can not be
+/// registered. Returns the created and changed files.
+pub fn new_day(root: &Path, day: u32, title: &str) -> Result<Vec<PathBuf>, Error> {
+    let dir = root.join(format!("day{day}")).join("rust");
+    if dir.exists() {
+        return Err(Error::Invalid(dir, "already exists".to_string()));
+    }
+    let registrations = [
+        root.join("Cargo.toml"),
+        root.join("aoc").join("Cargo.toml"),
+        root.join("aoc").join("src").join("days.rs"),
+    ]
+    .into_iter()
+    .map(|path| {
+        let text = read(&path)?;
+        match register(&text, day) {
+            Ok(text) => Ok((path, text)),
+            Err(message) => Err(Error::Invalid(path, message)),
+        }
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    let fill = |template: &str| {
+        template
+            .replace("dayN", &format!("day{day}"))
+            .replace("DayN", &format!("Day{day}"))
+            .replace("const DAY: u32 = N;", &format!("const DAY: u32 = {day};"))
+            .replace("\"TITLE\"", &format!("{title:?}"))
+    };
+    let files = [
+        (dir.join("Cargo.toml"), fill(MANIFEST)),
+        (dir.join("src").join("main.rs"), fill(MAIN)),
+        (dir.join("src").join("lib.rs"), fill(LIB)),
+    ];
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).map_err(|error| Error::Io(src, error))?;
+    let mut written = Vec::new();
+    for (path, text) in files.into_iter().chain(registrations) {
+        std::fs::write(&path, text).map_err(|error| Error::Io(path.clone(), error))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|error| Error::Io(path.to_path_buf(), error))
+}
+
+/// Number of the day a line of a day list is about, such as `"day7/rust",`,
+/// `day7.workspace = true` or `&day7::Day7,`.
+fn day_of(line: &str) -> Option<u32> {
+    let rest = line.trim_start().trim_start_matches(['"', '&']);
+    let digits = rest.strip_prefix("day")?;
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    let next = digits[end..].chars().next();
+    match next {
+        Some(c) if c.is_alphanumeric() || c == '_' => None,
+        _ => digits[..end].parse().ok(),
+    }
+}
+
+/// Adds `day` to every list of days in `text`, copying the line of the
+/// closest day before it. Lists of days sized by a `; N]` array type get
+/// their size bumped.
+fn register(text: &str, day: u32) -> Result<String, String> {
+    let mut lines = text.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut lists = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if day_of(&lines[i]).is_none() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < lines.len() && day_of(&lines[i]).is_some() {
+            i += 1;
+        }
+        lists.push(start..i);
+    }
+    if lists.is_empty() {
+        return Err("no list of days found".to_string());
+    }
+    // Later lists first, so inserting does not shift the earlier ones.
+    for list in lists.into_iter().rev() {
+        let days = lines[list.clone()]
+            .iter()
+            .filter_map(|line| day_of(line))
+            .collect::<Vec<_>>();
+        if days.contains(&day) {
+            return Err(format!("day {day} is already registered"));
+        }
+        let before = days.iter().filter(|&&d| d < day).count();
+        let (template, template_day) = match before {
+            0 => (&lines[list.start], days[0]),
+            n => (&lines[list.start + n - 1], days[n - 1]),
+        };
+        let line = template
+            .replace(&format!("day{template_day}"), &format!("day{day}"))
+            .replace(&format!("Day{template_day}"), &format!("Day{day}"));
+        lines.insert(list.start + before, line);
+        if list.start > 0 {
+            let header = &mut lines[list.start - 1];
+            let size = format!("; {}]", days.len());
+            if header.contains(&size) {
+                *header = header.replace(&size, &format!("; {}]", days.len() + 1));
+            }
+        }
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registering() {
+        assert_eq!(day_of("    \"day10/rust\","), Some(10));
+        assert_eq!(day_of("day3 = { path = \"day3/rust\" }"), Some(3));
+        assert_eq!(day_of("    &day12::Day12,"), Some(12));
+        assert_eq!(day_of("days.workspace = true"), None);
+        assert_eq!(day_of("day1x = 1"), None);
+
+        let days =
+            "pub const DAYS: [&dyn DynSolution; 2] = [\n    &day1::Day1,\n    &day3::Day3,\n];\n";
+        assert_eq!(
+            register(days, 2).unwrap(),
+            "pub const DAYS: [&dyn DynSolution; 3] = [\n    &day1::Day1,\n    &day2::Day2,\n    &day3::Day3,\n];\n"
+        );
+        let manifest = "members = [\n    \"aoc\",\n    \"day2/rust\",\n]\n\n[deps]\nday2 = { path = \"day2/rust\" }\nureq = \"2\"\n";
+        assert_eq!(
+            register(manifest, 13).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day2/rust\",\n    \"day13/rust\",\n]\n\n[deps]\nday2 = { path = \"day2/rust\" }\nday13 = { path = \"day13/rust\" }\nureq = \"2\"\n"
+        );
+        assert!(register(days, 3).is_err());
+        assert!(register("[deps]\n", 3).is_err());
+    }
+
+    #[test]
+    fn new_day_is_registered() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let real = aoc_core::input::root();
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::copy(real.join(file), path).unwrap();
+        }
+
+        let written = new_day(&root, 25, "Full of Hot Air").unwrap();
+        assert_eq!(written.len(), 6);
+        let lib = read(&root.join("day25/rust/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day25;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Full of Hot Air\";"));
+        assert!(lib.contains("const DAY: u32 = 25;"));
+        let days = read(&root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("    &day25::Day25,\n];"));
+        let workspace = read(&root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"day25/rust\""));
+        assert!(workspace.contains("day25 = { path = \"day25/rust\" }"));
+        let runner = read(&root.join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains("day25.workspace = true"));
+
+        assert!(new_day(&root, 25, "again").is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
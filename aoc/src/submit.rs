@@ -0,0 +1,483 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::Part;
+
+use crate::fetch::{self, Client, YEAR};
+
+/// Cooldown assumed after a wrong answer when the page does not state one.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+pub fn log_path() -> PathBuf {
+    aoc_core::input::root().join("submissions.log")
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::Wait => "wait",
+            Self::AlreadySolved => "already-solved",
+            Self::Unknown => "unknown",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "wrong" => Ok(Self::Wrong),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wait" => Ok(Self::Wait),
+            "already-solved" => Ok(Self::AlreadySolved),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub cooldown: Duration,
+    pub message: String,
+}
+
+impl Response {
+    /// Interprets the answer page, only looking at the text of its `<article>`.
+    pub fn parse(html: &str) -> Self {
+        let article = html
+            .split_once("<article")
+            .and_then(|(_, rest)| rest.split_once('>'))
+            .map(|(_, rest)| rest.split_once("</article>").map_or(rest, |(a, _)| a))
+            .unwrap_or(html);
+        let message = strip_tags(article);
+
+        let (outcome, cooldown) = if message.contains("That's the right answer") {
+            (Outcome::Correct, Duration::ZERO)
+        } else if message.contains("You gave an answer too recently") {
+            (
+                Outcome::Wait,
+                left_to_wait(&message).unwrap_or(DEFAULT_COOLDOWN),
+            )
+        } else if message.contains("That's not the right answer") {
+            let outcome = if message.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if message.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            };
+            (outcome, wait_before(&message).unwrap_or(DEFAULT_COOLDOWN))
+        } else if message.contains("Did you already complete it?") {
+            (Outcome::AlreadySolved, Duration::ZERO)
+        } else {
+            (Outcome::Unknown, Duration::ZERO)
+        };
+        Self {
+            outcome,
+            cooldown,
+            message,
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 4m 32s left to wait".
+fn left_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for part in time.split_whitespace() {
+        seconds += match part.split_at(part.len().checked_sub(1)?) {
+            (n, "h") => n.parse::<u64>().ok()? * 3600,
+            (n, "m") => n.parse::<u64>().ok()? * 60,
+            (n, "s") => n.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses "please wait one minute" and "please wait 5 minutes".
+fn wait_before(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: u64,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub cooldown: u64,
+}
+
+impl Attempt {
+    fn cooldown_end(&self) -> u64 {
+        self.time + self.cooldown
+    }
+
+    /// Whether this attempt proves `answer` wrong, taking too-high/too-low hints into account.
+    fn rules_out(&self, answer: &str) -> bool {
+        if !self.outcome.is_wrong() {
+            return false;
+        }
+        if self.answer == answer {
+            return true;
+        }
+        match (self.answer.parse::<i64>(), answer.parse::<i64>()) {
+            (Ok(previous), Ok(answer)) => match self.outcome {
+                Outcome::TooHigh => previous <= answer,
+                Outcome::TooLow => previous >= answer,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved(String),
+    KnownWrong(Attempt),
+    Cooldown(u64),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "already solved with {answer}"),
+            Self::KnownWrong(attempt) if attempt.outcome == Outcome::Wrong => {
+                write!(f, "{} was already rejected", attempt.answer)
+            }
+            Self::KnownWrong(attempt) => {
+                write!(
+                    f,
+                    "{} was already rejected as {}",
+                    attempt.answer, attempt.outcome
+                )
+            }
+            Self::Cooldown(seconds) => {
+                write!(
+                    f,
+                    "still cooling down, wait {}m {}s",
+                    seconds / 60,
+                    seconds % 60
+                )
+            }
+        }
+    }
+}
+
+/// Every submission made so far, stored as tab separated
+/// `time day part outcome cooldown answer` lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Log {
+    attempts: Vec<Attempt>,
+}
+
+impl Log {
+    /// Loads the log, a missing file being an empty log.
+    pub fn load(path: &Path) -> Result<Self, aoc_core::Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = aoc_core::input::read_path(path)?;
+        content
+            .parse()
+            .map_err(|message| aoc_core::Error::Invalid(path.to_path_buf(), message))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), aoc_core::Error> {
+        std::fs::write(path, self.to_string())
+            .map_err(|error| aoc_core::Error::Io(path.to_path_buf(), error))
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.day == day && a.part == part)
+        };
+        if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Refusal::Solved(correct.answer.clone()));
+        }
+        if let Some(wrong) = attempts().find(|a| a.rules_out(answer)) {
+            return Err(Refusal::KnownWrong(wrong.clone()));
+        }
+        match self.attempts.iter().map(Attempt::cooldown_end).max() {
+            Some(end) if end > now => Err(Refusal::Cooldown(end - now)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Log {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut log = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = || {
+                format!(
+                    "line {}: expected time, day, part, outcome, cooldown and answer",
+                    i + 1
+                )
+            };
+            let fields: Vec<_> = line.splitn(6, '\t').collect();
+            let [time, day, part, outcome, cooldown, answer] = fields[..] else {
+                return Err(invalid());
+            };
+            log.record(Attempt {
+                time: time.parse().map_err(|_| invalid())?,
+                day: day.parse().map_err(|_| invalid())?,
+                part: part
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(invalid)?,
+                answer: answer.to_string(),
+                outcome: outcome.parse().map_err(|_| invalid())?,
+                cooldown: cooldown.parse().map_err(|_| invalid())?,
+            });
+        }
+        Ok(log)
+    }
+}
+
+impl Display for Log {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for a in &self.attempts {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                a.time, a.day, a.part, a.outcome, a.cooldown, a.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidAnswer(String),
+    Refused(Refusal),
+    Fetch(fetch::Error),
+}
+
+impl From<fetch::Error> for Error {
+    fn from(error: fetch::Error) -> Self {
+        Self::Fetch(error)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidAnswer(answer) => write!(f, "cannot submit {answer:?}"),
+            Self::Refused(refusal) => write!(f, "not submitting: {refusal}"),
+            Self::Fetch(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Submits `answer` unless the log already rules it out, recording the attempt.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    day: u32,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Response, Error> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(Error::InvalidAnswer(answer.to_string()));
+    }
+    log.check(day, part, answer, now).map_err(Error::Refused)?;
+    let level = part.number().to_string();
+    let page = client.post_form(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let response = Response::parse(&page);
+    log.record(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: response.outcome,
+        cooldown: response.cooldown.as_secs(),
+    });
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn responses() {
+        let correct = Response::parse(&page(
+            "That's the right answer! You are <em>one gold star</em> closer.",
+        ));
+        assert_eq!(correct.outcome, Outcome::Correct);
+        assert_eq!(
+            correct.message,
+            "That's the right answer! You are one gold star closer."
+        );
+
+        let high = Response::parse(&page(
+            "That's not the right answer; your answer is too high. \
+             Please wait one minute before trying again.",
+        ));
+        assert_eq!(
+            (high.outcome, high.cooldown),
+            (Outcome::TooHigh, Duration::from_secs(60))
+        );
+
+        let low = Response::parse(&page(
+            "That's not the right answer; your answer is too low. \
+             Because you have guessed incorrectly 4 times on this puzzle, \
+             please wait 5 minutes before trying again.",
+        ));
+        assert_eq!(
+            (low.outcome, low.cooldown),
+            (Outcome::TooLow, Duration::from_secs(300))
+        );
+
+        let wrong = Response::parse(&page("That's not the right answer."));
+        assert_eq!(
+            (wrong.outcome, wrong.cooldown),
+            (Outcome::Wrong, DEFAULT_COOLDOWN)
+        );
+
+        let wait = Response::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 4m 32s left to wait.",
+        ));
+        assert_eq!(
+            (wait.outcome, wait.cooldown),
+            (Outcome::Wait, Duration::from_secs(272))
+        );
+
+        let solved = Response::parse(&page(
+            "You don't seem to be solving the right level. Did you already complete it?",
+        ));
+        assert_eq!(solved.outcome, Outcome::AlreadySolved);
+        assert_eq!(Response::parse("").outcome, Outcome::Unknown);
+    }
+
+    #[test]
+    fn log() {
+        let attempt = |time, answer: &str, outcome, cooldown| Attempt {
+            time,
+            day: 1,
+            part: Part::One,
+            answer: answer.to_string(),
+            outcome,
+            cooldown,
+        };
+        let mut log = Log::default();
+        log.record(attempt(100, "500", Outcome::TooHigh, 60));
+        log.record(attempt(200, "abc", Outcome::Wrong, 60));
+        assert_eq!(log.to_string().parse::<Log>(), Ok(log.clone()));
+
+        assert_eq!(
+            log.check(1, Part::One, "400", 230),
+            Err(Refusal::Cooldown(30))
+        );
+        assert_eq!(log.check(1, Part::One, "400", 260), Ok(()));
+        assert_eq!(log.check(1, Part::Two, "500", 260), Ok(()));
+        assert!(matches!(
+            log.check(1, Part::One, "600", 260),
+            Err(Refusal::KnownWrong(_))
+        ));
+        assert!(matches!(
+            log.check(1, Part::One, "abc", 260),
+            Err(Refusal::KnownWrong(_))
+        ));
+
+        log.record(attempt(300, "400", Outcome::Correct, 0));
+        assert_eq!(
+            log.check(1, Part::One, "400", 400),
+            Err(Refusal::Solved("400".to_string()))
+        );
+        assert!("1\t2\t3\tcorrect\t0\t4".parse::<Log>().is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::IsTerminal;
+
+use tracing_subscriber::{filter::LevelFilter, fmt::format::FmtSpan, EnvFilter};
+
+/// Environment variable with extra filter directives, such as `day12=trace`.
+pub const FILTER_VAR: &str = "AOC_LOG";
+
+fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Logs the events of the solvers and how long each span took to stderr, so
+/// answers on stdout stay clean. Only warnings by default, debug events from
+/// a verbosity of 1 and everything from 2, refined by the directives in
+/// `AOC_LOG`. Does nothing if a subscriber is already installed.
+pub fn init(verbosity: u8) {
+    let filter = EnvFilter::builder()
+        .with_default_directive(level(verbosity).into())
+        .with_env_var(FILTER_VAR)
+        .from_env_lossy();
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .try_init();
+}
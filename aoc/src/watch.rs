@@ -0,0 +1,200 @@
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::Part;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of every file below a set of paths.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Paths that do not exist are skipped, so creating them counts as a change.
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut snapshot = Self::default();
+        for path in paths {
+            snapshot.add(path);
+        }
+        snapshot
+    }
+
+    fn add(&mut self, path: &Path) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                self.add(&entry.path());
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            self.files.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    /// Files added, removed or modified since `older`.
+    pub fn changes<'a>(&'a self, older: &'a Self) -> impl Iterator<Item = &'a Path> {
+        let modified = self
+            .files
+            .iter()
+            .filter(|(path, time)| older.files.get(*path) != Some(time))
+            .map(|(path, _)| path.as_path());
+        let removed = older
+            .files
+            .keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .map(PathBuf::as_path);
+        modified.chain(removed)
+    }
+}
+
+/// Line by line difference, unchanged lines indented by two spaces and the
+/// others prefixed with `-` or `+`.
+pub fn diff(old: &str, new: &str) -> String {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+    // Length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff += &format!("  {}\n", old[i]);
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            diff += &format!("- {}\n", old[i]);
+            i += 1;
+        } else {
+            diff += &format!("+ {}\n", new[j]);
+            j += 1;
+        }
+    }
+    diff
+}
+
+/// Re-runs a day through cargo whenever its sources or input change, so edits
+/// to the solution are compiled in. Only returns if the screen can not be
+/// written to.
+pub fn watch(
+    day: u32,
+    part: Option<Part>,
+    input: &Path,
+    out: &mut impl Write,
+) -> std::io::Result<()> {
+    let root = aoc_core::input::root();
+    let paths = [root.join(format!("day{day}")).join("rust"), input.into()];
+    let mut args = vec!["run", "--quiet", "--package", "aoc", "--", "run"];
+    let day = day.to_string();
+    args.extend(["--day", &day, "--input"]);
+    let input = input.to_string_lossy();
+    args.push(&input);
+    let part = part.map(|part| part.to_string());
+    if let Some(part) = &part {
+        args.extend(["--part", part]);
+    }
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut snapshot = Snapshot::take(&paths);
+    let mut changed = Vec::new();
+    let mut previous = None::<String>;
+    loop {
+        write!(out, "\x1b[2J\x1b[H")?;
+        match changed.as_slice() {
+            [] => writeln!(out, "watching day {day}")?,
+            changed => writeln!(out, "watching day {day}, changed {}", changed.join(", "))?,
+        }
+        out.flush()?;
+        let answers = Command::new(&cargo).args(&args).current_dir(&root).output();
+        match answers {
+            Ok(answers) if answers.status.success() => {
+                let answers = String::from_utf8_lossy(&answers.stdout).into_owned();
+                match &previous {
+                    Some(previous) => write!(out, "{}", diff(previous, &answers))?,
+                    None => write!(out, "{answers}")?,
+                }
+                previous = Some(answers);
+            }
+            Ok(answers) => {
+                out.write_all(&answers.stdout)?;
+                out.write_all(&answers.stderr)?;
+            }
+            Err(error) => writeln!(out, "error: {}: {error}", cargo.to_string_lossy())?,
+        }
+        out.flush()?;
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = Snapshot::take(&paths);
+            changed = current
+                .changes(&snapshot)
+                .map(|path| {
+                    let path = path.strip_prefix(&root).unwrap_or(path);
+                    path.display().to_string()
+                })
+                .collect();
+            snapshot = current;
+            if !changed.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs() {
+        assert_eq!(diff("", ""), "");
+        assert_eq!(
+            diff(
+                "day 1\npart 1: 3\npart 2: 4\n",
+                "day 1\npart 1: 5\npart 2: 4\n"
+            ),
+            "  day 1\n- part 1: 3\n+ part 1: 5\n  part 2: 4\n"
+        );
+        assert_eq!(diff("a\n", "a\nb\n"), "  a\n+ b\n");
+        assert_eq!(diff("a\nb\n", "b\n"), "- a\n  b\n");
+    }
+
+    #[test]
+    fn snapshots() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        let file = dir.join("src/lib.rs");
+        let missing = dir.join("input.txt");
+        let paths = [dir.clone(), missing.clone()];
+
+        let empty = Snapshot::take(&paths);
+        std::fs::write(&file, "1").unwrap();
+        let written = Snapshot::take(&paths);
+        assert_eq!(written.changes(&empty).collect::<Vec<_>>(), vec![&file]);
+        assert_eq!(written.changes(&written).count(), 0);
+
+        std::fs::write(&missing, "2").unwrap();
+        std::fs::remove_file(&file).unwrap();
+        let moved = Snapshot::take(&paths);
+        let mut changes = moved.changes(&written).collect::<Vec<_>>();
+        changes.sort();
+        assert_eq!(changes, vec![&missing, &file]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+// Each test binary only uses part of these helpers.
+#![allow(dead_code)]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+};
+
+/// Minimal HTTP server answering successive connections with canned responses.
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StubServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+        let recorded = requests.clone();
+        let handle = std::thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; length];
+                if reader.read_exact(&mut content).is_err() {
+                    return;
+                }
+                request.push_str("\r\n");
+                request.push_str(&String::from_utf8(content).unwrap());
+                recorded.lock().unwrap().push(request);
+
+                let mut stream = reader.into_inner();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        Self {
+            url,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    /// Raw requests received so far, headers followed by the body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Case insensitive lookup of a header in a recorded request.
+    pub fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+        request.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        // Unblock the accept loop if not every response was consumed.
+        if let Some(handle) = self.handle.take() {
+            if !handle.is_finished() {
+                let _ = std::net::TcpStream::connect(self.url.trim_start_matches("http://"));
+            }
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Fresh scratch directory in the system temp dir.
+pub fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
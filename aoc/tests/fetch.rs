@@ -0,0 +1,55 @@
+mod common;
+
+use aoc::fetch::{self, Client, Error, USER_AGENT};
+use common::StubServer;
+
+#[test]
+fn downloads_once_and_caches() {
+    let server = StubServer::start(vec![(200, "1000\n2000\n")]);
+    let path = common::temp_dir("fetch-cache").join("day1/input.txt");
+    let client = || Ok(Client::new(&server.url, "secret"));
+
+    assert_eq!(
+        fetch::cached_input(1, &path, client).unwrap(),
+        "1000\n2000\n"
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+    // The second read must come from the cache, not the server.
+    assert_eq!(
+        fetch::cached_input(1, &path, client).unwrap(),
+        "1000\n2000\n"
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+    assert_eq!(
+        StubServer::header(&requests[0], "cookie"),
+        Some("session=secret")
+    );
+    assert_eq!(
+        StubServer::header(&requests[0], "user-agent"),
+        Some(USER_AGENT)
+    );
+}
+
+#[test]
+fn cached_input_does_not_need_a_session() {
+    let path = common::temp_dir("fetch-offline").join("input.txt");
+    std::fs::write(&path, "cached\r\n").unwrap();
+    let client = || Err(Error::MissingSession(None));
+    assert_eq!(fetch::cached_input(3, &path, client).unwrap(), "cached\n");
+}
+
+#[test]
+fn failed_download_is_not_cached() {
+    let server = StubServer::start(vec![(400, "Please log in")]);
+    let path = common::temp_dir("fetch-failure").join("input.txt");
+    let client = || Ok(Client::new(&server.url, "expired"));
+
+    match fetch::cached_input(5, &path, client) {
+        Err(Error::Status(400, url)) => assert!(url.ends_with("/2022/day/5/input")),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(!path.exists());
+}
@@ -0,0 +1,37 @@
+use aoc::days::DAYS;
+use aoc_core::Part;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    for day in DAYS {
+        for seed in 0..4 {
+            for size in [0, 1, 7, 40] {
+                let input = day.generate(seed, size);
+                let parsed = day.parse(&input).unwrap_or_else(|e| {
+                    panic!("seed {seed}, size {size}: {e}\n{input}");
+                });
+                for part in Part::ALL {
+                    parsed.solve(part);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generation_is_deterministic() {
+    for day in DAYS {
+        assert_eq!(
+            day.generate(42, 30),
+            day.generate(42, 30),
+            "day {}",
+            day.day()
+        );
+        assert_ne!(
+            day.generate(42, 30),
+            day.generate(43, 30),
+            "day {}",
+            day.day()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+const CALORIES: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn solves_from_any_reader() {
+    let input = aoc_core::input::read_from(CALORIES.as_bytes()).unwrap();
+    let answers = aoc_core::answers::<day1::Day1>(&input).unwrap();
+    assert_eq!(answers.map(|a| a.to_string()), ["24000", "45000"]);
+}
+
+#[test]
+fn runs_on_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(CALORIES.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "day 1: Calorie Counting\npart 1: 24000\npart 2: 45000\n"
+    );
+}
+
+#[test]
+fn saved_variants_give_identical_answers() {
+    for day in aoc::days::DAYS {
+        let input = day.generate(1, 20);
+        let answers = |input: &str| {
+            let parsed = day.parse(input).unwrap();
+            aoc_core::Part::ALL.map(|part| parsed.solve(part))
+        };
+        let expected = answers(&input);
+        let variants = [
+            input.replace('\n', "\r\n"),
+            format!("\u{feff}{input}"),
+            input.trim_end().to_string(),
+            input.replace('\n', " \n") + "\n\n",
+        ];
+        for variant in variants {
+            let normalized = aoc_core::input::normalize(&variant);
+            assert_eq!(answers(&normalized), expected, "day {}", day.day());
+        }
+    }
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use aoc::{
+    fetch::Client,
+    submit::{self, Error, Log, Outcome, Refusal},
+};
+use aoc_core::Part;
+use common::StubServer;
+
+fn page(article: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n\
+         <article><p>{article}</p></article>\n</main>\n</body>\n</html>\n"
+    )
+}
+
+#[test]
+fn correct_answer_is_posted_once() {
+    let correct = page(
+        "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.",
+    );
+    let server = StubServer::start(vec![(200, &correct)]);
+    let client = Client::new(&server.url, "secret");
+    let mut log = Log::default();
+
+    let response = submit::submit(&client, &mut log, 1, Part::One, "72511", 1000).unwrap();
+    assert_eq!(response.outcome, Outcome::Correct);
+    match submit::submit(&client, &mut log, 1, Part::One, "72511", 2000) {
+        Err(Error::Refused(Refusal::Solved(answer))) => assert_eq!(answer, "72511"),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+    assert_eq!(
+        StubServer::header(&requests[0], "cookie"),
+        Some("session=secret")
+    );
+    assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=72511"));
+}
+
+#[test]
+fn wrong_answers_and_cooldowns() {
+    let too_high = page(
+        "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+         you're using the full input data.  Please wait one minute before trying again.",
+    );
+    let too_low = page(
+        "That's not the right answer; your answer is too low.  Because you have guessed \
+         incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.",
+    );
+    let server = StubServer::start(vec![(200, &too_high), (200, &too_low)]);
+    let client = Client::new(&server.url, "secret");
+    let mut log = Log::default();
+
+    let response = submit::submit(&client, &mut log, 7, Part::Two, "5000", 1000).unwrap();
+    assert_eq!(response.outcome, Outcome::TooHigh);
+    assert!(matches!(
+        submit::submit(&client, &mut log, 7, Part::Two, "4000", 1030),
+        Err(Error::Refused(Refusal::Cooldown(30)))
+    ));
+    for known in ["5000", "6000"] {
+        assert!(matches!(
+            submit::submit(&client, &mut log, 7, Part::Two, known, 1100),
+            Err(Error::Refused(Refusal::KnownWrong(_)))
+        ));
+    }
+    let response = submit::submit(&client, &mut log, 7, Part::Two, "4000", 1100).unwrap();
+    assert_eq!(response.outcome, Outcome::TooLow);
+    assert!(matches!(
+        submit::submit(&client, &mut log, 7, Part::Two, "4500", 1300),
+        Err(Error::Refused(Refusal::Cooldown(100)))
+    ));
+
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(log.attempts().len(), 2);
+    assert_eq!(log.to_string().parse::<Log>().unwrap(), log);
+}
+
+#[test]
+fn submitting_too_early_starts_a_cooldown() {
+    let wait = page(
+        "You gave an answer too recently; you have to wait after submitting an answer \
+         before trying again.  You have 4m 32s left to wait. <a href=\"/2022/day/3\">[Return to Day 3]</a>",
+    );
+    let server = StubServer::start(vec![(200, &wait)]);
+    let client = Client::new(&server.url, "secret");
+    let mut log = Log::default();
+
+    let response = submit::submit(&client, &mut log, 3, Part::One, "8105", 1000).unwrap();
+    assert_eq!(response.outcome, Outcome::Wait);
+    assert!(matches!(
+        submit::submit(&client, &mut log, 3, Part::One, "8105", 1100),
+        Err(Error::Refused(Refusal::Cooldown(172)))
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,17 @@
+use aoc::days::DAYS;
+use aoc_core::answers::{self, Answers, Verdict};
+
+#[test]
+fn real_inputs_match_answers() {
+    let answers = Answers::load(&answers::path()).unwrap();
+    let mut mismatches = Vec::new();
+    for day in DAYS {
+        let input = aoc_core::input::read(day.day()).unwrap();
+        for check in answers::verify(day, &input, &answers).unwrap() {
+            if check.verdict != Verdict::Correct {
+                mismatches.push(check);
+            }
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
@@ -0,0 +1,132 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+    thread::LocalKey,
+};
+
+/// Allocator counting the allocations of every thread on top of the system
+/// one. Binaries opt in with
+/// `#[global_allocator] static ALLOCATOR: Counting = Counting;`.
+pub struct Counting;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static BYTES: Cell<usize> = const { Cell::new(0) };
+    // Memory freed by another thread than the one allocating it makes this
+    // drift, which is why it is signed.
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn update<T: Copy>(counter: &'static LocalKey<Cell<T>>, f: impl FnOnce(T) -> T) {
+    // Fails only while the thread is being torn down.
+    let _ = counter.try_with(|cell| cell.set(f(cell.get())));
+}
+
+fn get<T: Copy + Default>(counter: &'static LocalKey<Cell<T>>) -> T {
+    counter.try_with(Cell::get).unwrap_or_default()
+}
+
+fn record(allocated: usize, freed: usize) {
+    update(&ALLOCATIONS, |n| n + 1);
+    update(&BYTES, |bytes| bytes + allocated);
+    update(&LIVE, |live| live + allocated as isize - freed as isize);
+    let live = get(&LIVE);
+    update(&PEAK, |peak| peak.max(live));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        update(&LIVE, |live| live - layout.size() as isize);
+    }
+
+    /// Counts as a new allocation of `new_size` bytes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record(new_size, layout.size());
+        }
+        new
+    }
+}
+
+/// Whether `Counting` is the global allocator.
+pub fn installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// What the current thread allocated while running a closure.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest amount of memory live at once, on top of what was live before.
+    pub peak: usize,
+}
+
+/// Runs `f` and counts its allocations, all zero unless `Counting` is
+/// installed. Only allocations of the calling thread are counted.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let (allocations, bytes, live) = (get(&ALLOCATIONS), get(&BYTES), get(&LIVE));
+    update(&PEAK, |_| live);
+    let result = f();
+    let usage = Usage {
+        allocations: get(&ALLOCATIONS) - allocations,
+        bytes: get(&BYTES) - bytes,
+        peak: (get(&PEAK) - live).max(0) as usize,
+    };
+    (result, usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn counting() {
+        let (sum, usage) = measure(|| {
+            let mut total = 0;
+            for n in 0..10 {
+                let numbers = vec![n as u64; 1000];
+                total += numbers.iter().sum::<u64>();
+            }
+            let kept = vec![0u8; 100];
+            (total, kept)
+        });
+        assert!(installed());
+        assert_eq!(sum.0, 45_000);
+        assert_eq!(usage.allocations, 11);
+        assert_eq!(usage.bytes, 10 * 8000 + 100);
+        assert_eq!(usage.peak, 8000);
+
+        let (_, usage) = measure(|| 1 + 1);
+        assert_eq!(usage, Usage::default());
+        let (_, usage) = measure(|| drop(sum));
+        assert_eq!((usage.allocations, usage.peak), (0, 0));
+    }
+}
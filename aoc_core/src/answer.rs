@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use crate::{Grid, Pos};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    Bitmap(Bitmap),
+    /// The input has no answer for the part, with the reason why.
+    Unsolved(&'static str),
+}
+
+impl Answer {
+    /// Name of the variant, as used in machine readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Int(_) => "int",
+            Self::Str(_) => "str",
+            Self::Bitmap(_) => "bitmap",
+            Self::Unsolved(_) => "unsolved",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Str(s) => write!(f, "{s}"),
+            Self::Bitmap(bitmap) => write!(f, "{bitmap}"),
+            Self::Unsolved(reason) => write!(f, "unsolved, {reason}"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Int(i64::try_from(n).expect("answer does not fit into i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<Bitmap> for Answer {
+    fn from(bitmap: Bitmap) -> Self {
+        Self::Bitmap(bitmap)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    pixels: Grid<bool>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: Grid::new(width, height, false),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[Pos::new(x, y)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.pixels[Pos::new(x, y)] = value;
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+}
+
+impl From<Grid<bool>> for Bitmap {
+    fn from(pixels: Grid<bool>) -> Self {
+        Self { pixels }
+    }
+}
+
+impl Display for Bitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.pixels.map(|&lit| if lit { '#' } else { '.' });
+        write!(f, "{pixels}")
+    }
+}
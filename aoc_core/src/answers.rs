@@ -0,0 +1,228 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::{Answer, DynSolution, Error, ParseError, Part};
+
+pub fn path() -> PathBuf {
+    crate::input::root().join("answers.toml")
+}
+
+/// Known correct answers, stored in a small subset of toml:
+/// `[dayN]` tables with `part_1`/`part_2` string keys.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = crate::input::read_path(path)?;
+        content
+            .parse()
+            .map_err(|message| Error::Invalid(path.to_path_buf(), message))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        std::fs::write(path, self.to_string()).map_err(|error| Error::Io(path.to_path_buf(), error))
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &Answer) -> Verdict {
+        if let Answer::Unsolved(reason) = answer {
+            return Verdict::Unsolved(reason);
+        }
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let invalid = |message: &str| format!("line {}: {message}", i + 1);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.strip_prefix("day"))
+                    .and_then(|number| number.parse::<u32>().ok())
+                    .ok_or_else(|| invalid("expected a [dayN] table"))?;
+                day = Some(number);
+                continue;
+            }
+            let day = day.ok_or_else(|| invalid("expected a [dayN] table before answers"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid("expected part_N = \"answer\""))?;
+            let part = match key.trim() {
+                "part_1" => Part::One,
+                "part_2" => Part::Two,
+                _ => return Err(invalid("expected part_1 or part_2")),
+            };
+            let value =
+                unescape(value.trim()).ok_or_else(|| invalid("expected a quoted string"))?;
+            answers.answers.insert((day, part), value);
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut last_day = None;
+        for ((day, part), answer) in &self.answers {
+            if last_day != Some(*day) {
+                if last_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day}]")?;
+                last_day = Some(*day);
+            }
+            writeln!(f, "part_{part} = {}", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let s = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                'n' => '\n',
+                't' => '\t',
+                _ => return None,
+            }),
+            '"' => return None,
+            c => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown,
+    /// There is no answer to compare or record.
+    Unsolved(&'static str),
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+pub fn verify(
+    day: &dyn DynSolution,
+    input: &str,
+    answers: &Answers,
+) -> Result<Vec<Check>, ParseError> {
+    let parsed = day.parse(input)?;
+    Ok(Part::ALL
+        .into_iter()
+        .map(|part| {
+            let answer = parsed.solve(part);
+            let verdict = answers.check(day.day(), part, &answer);
+            Check {
+                day: day.day(),
+                part,
+                answer,
+                verdict,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bitmap;
+
+    #[test]
+    fn round_trip() {
+        let mut bitmap = Bitmap::new(2, 2);
+        bitmap.set(0, 0, true);
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, &Answer::Int(42));
+        answers.insert(5, Part::Two, &Answer::Str("say \"hi\"".to_string()));
+        answers.insert(10, Part::Two, &Answer::Bitmap(bitmap.clone()));
+
+        let s = answers.to_string();
+        assert_eq!(
+            s,
+            "[day1]\npart_1 = \"42\"\n\n[day5]\npart_2 = \"say \\\"hi\\\"\"\n\n[day10]\npart_2 = \"#.\\n..\"\n"
+        );
+        assert_eq!(s.parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn checks() {
+        let answers = "# comment\n[day1]\npart_1 = \"42\""
+            .parse::<Answers>()
+            .unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Int(42)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Int(7)),
+            Verdict::Wrong("42".to_string())
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Int(7)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Unsolved("no path")),
+            Verdict::Unsolved("no path")
+        );
+
+        assert!("part_1 = \"42\"".parse::<Answers>().is_err());
+        assert!("[day1]\npart_3 = \"42\"".parse::<Answers>().is_err());
+        assert!("[day1]\npart_1 = 42".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc::{self, Usage},
+    DynSolution, Error, ParseError, Part,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)];
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub day: u32,
+    pub stage: Stage,
+    pub timing: Timing,
+    /// Allocations of a single run, only known with the counting allocator.
+    pub usage: Option<Usage>,
+}
+
+fn measure(config: &Config, mut f: impl FnMut()) -> Timing {
+    for _ in 0..config.warmup {
+        f();
+    }
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            f();
+            now.elapsed()
+        })
+        .collect();
+    Timing::from_samples(samples)
+}
+
+pub fn bench(
+    day: &dyn DynSolution,
+    input: &str,
+    config: &Config,
+) -> Result<Vec<Measurement>, ParseError> {
+    let parsed = day.parse(input)?;
+    Ok(Stage::ALL
+        .into_iter()
+        .map(|stage| {
+            let run = || match stage {
+                Stage::Parse => drop(black_box(day.parse(black_box(input)))),
+                Stage::Part(part) => drop(black_box(parsed.solve(part))),
+            };
+            Measurement {
+                day: day.day(),
+                stage,
+                timing: measure(config, run),
+                usage: alloc::installed().then(|| alloc::measure(run).1),
+            }
+        })
+        .collect())
+}
+
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: HashMap<(u32, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Self {
+        Self {
+            medians: measurements
+                .iter()
+                .map(|m| ((m.day, m.stage), m.timing.median))
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = crate::input::read_path(path)?;
+        let invalid = |line: &str| {
+            Error::Invalid(
+                path.to_path_buf(),
+                format!("invalid baseline entry: {line:?}"),
+            )
+        };
+        let mut medians = HashMap::new();
+        for line in content.lines() {
+            let mut fields = line.split('\t');
+            let (Some(day), Some(stage), Some(nanos), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid(line));
+            };
+            let day = day.parse().map_err(|_| invalid(line))?;
+            let stage = Stage::ALL
+                .into_iter()
+                .find(|s| s.to_string() == stage)
+                .ok_or_else(|| invalid(line))?;
+            let nanos = nanos.parse().map_err(|_| invalid(line))?;
+            medians.insert((day, stage), Duration::from_nanos(nanos));
+        }
+        Ok(Self { medians })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let mut entries = self.medians.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|((day, stage), _)| (*day, Stage::ALL.iter().position(|s| s == stage)));
+        let content = entries
+            .into_iter()
+            .map(|((day, stage), median)| format!("{day}\t{stage}\t{}\n", median.as_nanos()))
+            .collect::<String>();
+        std::fs::write(path, content).map_err(|error| Error::Io(path.to_path_buf(), error))
+    }
+
+    pub fn median(&self, day: u32, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+}
+
+pub fn report(measurements: &[Measurement], baseline: Option<&Baseline>) -> String {
+    let usage = measurements.iter().any(|m| m.usage.is_some());
+    let mut report = format!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "median", "min", "max"
+    );
+    if baseline.is_some() {
+        report += &format!("  {:>10}", "change");
+    }
+    if usage {
+        report += &format!("  {:>10}  {:>10}  {:>10}", "allocs", "bytes", "peak");
+    }
+    report.push('\n');
+    for m in measurements {
+        report += &format!(
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+            m.day,
+            m.stage.to_string(),
+            format!("{:.1?}", m.timing.median),
+            format!("{:.1?}", m.timing.min),
+            format!("{:.1?}", m.timing.max),
+        );
+        if let Some(baseline) = baseline {
+            let change = match baseline.median(m.day, m.stage) {
+                Some(old) if !old.is_zero() => {
+                    let ratio = m.timing.median.as_secs_f64() / old.as_secs_f64();
+                    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+                }
+                _ => "-".to_string(),
+            };
+            report += &format!("  {change:>10}");
+        }
+        if usage {
+            let usage = m.usage.unwrap_or_default();
+            report += &format!(
+                "  {:>10}  {:>10}  {:>10}",
+                usage.allocations,
+                bytes(usage.bytes),
+                bytes(usage.peak)
+            );
+        }
+        report.push('\n');
+    }
+    report
+}
+
+/// `bytes` with a binary unit, such as `1.5KiB`.
+fn bytes(bytes: usize) -> String {
+    let mut size = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if size < 1024.0 {
+            return match unit {
+                "B" => format!("{bytes}B"),
+                unit => format!("{size:.1}{unit}"),
+            };
+        }
+        size /= 1024.0;
+    }
+    format!("{size:.1}GiB")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timing() {
+        let ms = Duration::from_millis;
+        let timing = Timing::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            timing,
+            Timing {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        let timing = Timing::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(timing.median, ms(3));
+    }
+
+    #[test]
+    fn baseline() {
+        let timing = |n| Timing {
+            min: Duration::from_nanos(n),
+            median: Duration::from_nanos(n),
+            max: Duration::from_nanos(n),
+        };
+        let measurements = [
+            Measurement {
+                day: 1,
+                stage: Stage::Parse,
+                timing: timing(100),
+                usage: None,
+            },
+            Measurement {
+                day: 1,
+                stage: Stage::Part(Part::Two),
+                timing: timing(200),
+                usage: None,
+            },
+        ];
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}", std::process::id()));
+        Baseline::from_measurements(&measurements)
+            .save(&path)
+            .unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            baseline.median(1, Stage::Parse),
+            Some(Duration::from_nanos(100))
+        );
+        assert_eq!(
+            baseline.median(1, Stage::Part(Part::Two)),
+            Some(Duration::from_nanos(200))
+        );
+        assert_eq!(baseline.median(1, Stage::Part(Part::One)), None);
+
+        let report = report(&measurements, Some(&baseline));
+        assert!(report.lines().nth(1).unwrap().ends_with("+0.0%"));
+    }
+
+    #[test]
+    fn usage() {
+        let usage = Usage {
+            allocations: 3,
+            bytes: 1536,
+            peak: 1024,
+        };
+        let measurement = Measurement {
+            day: 3,
+            stage: Stage::Parse,
+            timing: Timing::from_samples(vec![Duration::from_nanos(5)]),
+            usage: Some(usage),
+        };
+        let report = report(&[measurement], None);
+        assert!(report
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with("allocs       bytes        peak"));
+        assert!(report
+            .lines()
+            .nth(1)
+            .unwrap()
+            .ends_with("3      1.5KiB      1.0KiB"));
+        assert_eq!(bytes(1023), "1023B");
+        assert_eq!(bytes(3 << 30), "3.0GiB");
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::{Debug, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::Rng;
+
+/// Values that know how to produce simpler versions of themselves, so a
+/// failing input can be reduced to a minimal reproducer.
+pub trait Shrink: Clone {
+    /// Strictly simpler candidates, most aggressive first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = vec![0, self / 2, self.saturating_sub(1)];
+                candidates.retain(|c| c < self);
+                candidates.dedup();
+                candidates
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_shrink_signed {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = vec![0, -self.signum(), self / 2, self - self.signum()];
+                candidates.retain(|c| c.unsigned_abs() < self.unsigned_abs());
+                candidates.dedup();
+                candidates
+            }
+        }
+    )*};
+}
+
+impl_shrink_unsigned!(u8, u16, u32, u64, usize);
+impl_shrink_signed!(i8, i16, i32, i64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            true => vec![false],
+            false => vec![],
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        if !self.is_empty() {
+            candidates.push(Vec::new());
+        }
+        if self.len() > 1 {
+            candidates.push(self[..self.len() / 2].to_vec());
+            candidates.push(self[self.len() / 2..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut smaller = self.clone();
+            smaller.remove(i);
+            candidates.push(smaller);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut candidate = self.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+macro_rules! impl_shrink_tuple {
+    ($($name:ident $index:tt),*) => {
+        impl<$($name: Shrink),*> Shrink for ($($name,)*) {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = Vec::new();
+                $(
+                    for simpler in self.$index.shrink() {
+                        let mut candidate = self.clone();
+                        candidate.$index = simpler;
+                        candidates.push(candidate);
+                    }
+                )*
+                candidates
+            }
+        }
+    };
+}
+
+impl_shrink_tuple!(A 0, B 1);
+impl_shrink_tuple!(A 0, B 1, C 2);
+impl_shrink_tuple!(A 0, B 1, C 2, D 3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub cases: u32,
+    /// Size handed to the generator grows linearly up to this.
+    pub max_size: usize,
+    pub seed: u64,
+    pub max_shrinks: u32,
+}
+
+impl Default for Config {
+    /// The seed can be changed with `AOC_CHECK_SEED` to explore other cases.
+    fn default() -> Self {
+        let seed = std::env::var("AOC_CHECK_SEED")
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+            .unwrap_or(0);
+        Self {
+            cases: 256,
+            max_size: 32,
+            seed,
+            max_shrinks: 10_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub case: u32,
+    pub shrinks: u32,
+    pub input: T,
+    pub message: String,
+}
+
+impl<T: Debug> Display for Failure<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed at case {} with AOC_CHECK_SEED={}, shrunk {} times to\n{:?}\n{}",
+            self.case, self.seed, self.shrinks, self.input, self.message
+        )
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `property`, turning a panic into a failure. Panic messages of the
+/// checked code are not printed, as shrinking may trigger a lot of them.
+fn run_property<T>(property: &impl Fn(&T) -> Result<(), String>, input: &T) -> Result<(), String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                default(info);
+            }
+        }));
+    });
+    QUIET.with(|quiet| quiet.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| property(input)));
+    QUIET.with(|quiet| quiet.set(false));
+    result.unwrap_or_else(|payload| Err(panic_message(payload.as_ref())))
+}
+
+/// Describes a caught panic by its message, when it has a textual one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    format!("panicked: {message}")
+}
+
+/// Checks `property` on generated inputs, returning the shrunk failure if any.
+pub fn run<T: Shrink>(
+    config: &Config,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Failure<T>> {
+    let mut rng = Rng::new(config.seed);
+    for case in 0..config.cases {
+        let size = config.max_size * case as usize / config.cases.max(1) as usize;
+        let input = generate(&mut rng, size);
+        let Err(message) = run_property(&property, &input) else {
+            continue;
+        };
+        let mut failure = Failure {
+            seed: config.seed,
+            case,
+            shrinks: 0,
+            input,
+            message,
+        };
+        'shrinking: while failure.shrinks < config.max_shrinks {
+            for candidate in failure.input.shrink() {
+                if let Err(message) = run_property(&property, &candidate) {
+                    failure.input = candidate;
+                    failure.message = message;
+                    failure.shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        return Err(failure);
+    }
+    Ok(())
+}
+
+/// Panics with a minimal reproducer if `property` fails on a generated input.
+pub fn check<T: Shrink + Debug>(
+    name: &str,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Err(failure) = run(&Config::default(), generate, property) {
+        panic!("{name} {failure}");
+    }
+}
+
+/// Checks that an optimized routine agrees with a naive oracle.
+pub fn differential<T: Shrink + Debug, R: PartialEq + Debug>(
+    name: &str,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    oracle: impl Fn(&T) -> R,
+    subject: impl Fn(&T) -> R,
+) {
+    check(name, generate, |input| {
+        let expected = oracle(input);
+        let actual = subject(input);
+        match expected == actual {
+            true => Ok(()),
+            false => Err(format!("oracle: {expected:?}\nsubject: {actual:?}")),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinking() {
+        let config = Config {
+            seed: 1,
+            ..Config::default()
+        };
+        let numbers = |rng: &mut Rng, size| {
+            (0..size)
+                .map(|_| rng.range(0..=100) as u32)
+                .collect::<Vec<_>>()
+        };
+        let failure = run(&config, numbers, |numbers| {
+            match numbers.iter().sum::<u32>() < 150 {
+                true => Ok(()),
+                false => Err("too big".to_string()),
+            }
+        })
+        .unwrap_err();
+        assert_eq!(failure.input.iter().sum::<u32>(), 150);
+        assert!(failure.input.len() <= 2);
+
+        let failure = run(&config, numbers, |numbers| {
+            assert!(!numbers.contains(&7));
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(failure.input, vec![7]);
+        assert!(failure.message.starts_with("panicked"));
+
+        assert!(run(&config, numbers, |_| Ok(())).is_ok());
+    }
+
+    #[test]
+    fn shrink_candidates() {
+        assert_eq!(10u8.shrink(), vec![0, 5, 9]);
+        assert_eq!((-3i32).shrink(), vec![0, 1, -1, -2]);
+        assert!(0u32.shrink().is_empty());
+        assert!(Vec::<u8>::new().shrink().is_empty());
+        assert!((1u8, true).shrink().contains(&(1, false)));
+    }
+}
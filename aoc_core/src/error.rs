@@ -0,0 +1,141 @@
+use std::{fmt::Display, path::PathBuf};
+
+use crate::Part;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, std::io::Error),
+    Invalid(PathBuf, String),
+    Parse(ParseError),
+    Unsolved(u32, Part, &'static str),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            Self::Invalid(path, message) => write!(f, "{}: {message}", path.display()),
+            Self::Parse(error) => write!(f, "{error}"),
+            Self::Unsolved(day, part, reason) => {
+                write!(f, "day {day} part {part} has no answer: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: &'static str,
+}
+
+impl ParseError {
+    /// `token` has to be a subslice of `input`, its position inside `input`
+    /// becomes the line and column of the error.
+    pub fn new(day: u32, input: &str, token: &str, expected: &'static str) -> Self {
+        let (line, column) = position(input, token);
+        Self {
+            day,
+            line,
+            column,
+            text: token.to_string(),
+            expected,
+        }
+    }
+
+    /// Error for a token that is missing at the very end of `input`.
+    pub fn missing(day: u32, input: &str, expected: &'static str) -> Self {
+        Self::new(day, input, &input[input.len()..], expected)
+    }
+
+    /// Rebases an error produced while parsing `inner` onto `outer`,
+    /// where `inner` is a subslice of `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "end of input")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_token<T: std::str::FromStr>(
+    day: u32,
+    input: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(day, input, token, expected))
+}
+
+fn position(input: &str, token: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| *offset <= input.len())
+        .unwrap_or(0);
+    let prefix = &input[..offset];
+    let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+    let line = prefix.matches('\n').count() + 1;
+    let column = prefix[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let input = "move 1 from 2 to 3\nmove x from 2 to 3";
+        let line = input.lines().nth(1).unwrap();
+        let token = line.split_whitespace().nth(1).unwrap();
+
+        let error = ParseError::new(5, line, token, "a number");
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = error.within(input, line);
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 2, column 6: expected a number, found \"x\""
+        );
+
+        let error = ParseError::missing(5, line, "a number");
+        assert_eq!((error.line, error.column), (1, 19));
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 1, column 19: expected a number, found end of input"
+        );
+    }
+}
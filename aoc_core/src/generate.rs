@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+
+/// Random inputs in the exact format [`Solution::parse`] accepts.
+pub trait Generate: Solution {
+    /// Writes an input scaled by `size`, what `size` counts is up to the day.
+    /// The same `rng` state always produces the same input.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// SplitMix64, small and good enough to get the same input for the same seed
+/// on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`, `n` has to be non zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n != 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        let len = end.abs_diff(start).wrapping_add(1);
+        match len {
+            0 => self.next_u64() as i64,
+            len => start.wrapping_add(self.below(len) as i64),
+        }
+    }
+
+    /// Uniform index into a slice of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    pub fn lowercase(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let values = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(values, (0..100).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(8).next_u64(), values[0]);
+
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let value = rng.range(-3..=3);
+            assert!((-3..=3).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(i64::MIN..=i64::MAX);
+
+        let mut items = (0..50).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on a 2D plane. Grids use unsigned points with `y` growing
+/// downwards, unbounded planes use signed ones with the same orientation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A displacement between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Pos<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn cast<U: From<T>>(self) -> Pos<U> {
+        Pos::new(self.x.into(), self.y.into())
+    }
+
+    /// `None` if a coordinate does not fit into `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Pos<U>> {
+        Some(Pos::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Pos<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Number of king moves between the two points.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl Pos<usize> {
+    /// `None` if the result would have a negative coordinate.
+    pub fn checked_add(self, vector: Vector<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+}
+
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    match a < b {
+        true => b - a,
+        false => a - b,
+    }
+}
+
+impl<T> Vector<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn cast<U: From<T>>(self) -> Vector<U> {
+        Vector::new(self.x.into(), self.y.into())
+    }
+
+    /// `None` if a component does not fit into `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vector<U>> {
+        Some(Vector::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T> From<Pos<T>> for Vector<T> {
+    /// The displacement from the origin.
+    fn from(pos: Pos<T>) -> Self {
+        Self::new(pos.x, pos.y)
+    }
+}
+
+impl<T> From<Vector<T>> for Pos<T> {
+    fn from(vector: Vector<T>) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector<T>> for Pos<T> {
+    type Output = Self;
+
+    fn add(self, vector: Vector<T>) -> Self {
+        Self::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector<T>> for Pos<T> {
+    type Output = Self;
+
+    fn sub(self, vector: Vector<T>) -> Self {
+        Self::new(self.x - vector.x, self.y - vector.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Pos<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vector<T>> for Pos<T> {
+    fn add_assign(&mut self, vector: Vector<T>) {
+        self.x += vector.x;
+        self.y += vector.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vector<T>> for Pos<T> {
+    fn sub_assign(&mut self, vector: Vector<T>) {
+        self.x -= vector.x;
+        self.y -= vector.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vector<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    /// Clockwise, starting from the right.
+    pub const ALL: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl<T: From<i8>> From<Direction> for Vector<T> {
+    /// A single step, `Up` decreasing `y`.
+    fn from(direction: Direction) -> Self {
+        let (x, y) = match direction {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+        };
+        Self::new(x.into(), y.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Pos::new(1, -2);
+        let b = Pos::new(-3, 4);
+        let v = b - a;
+        assert_eq!(v, Vector::new(-4, 6));
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(v * 2 + -v, v);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Pos::new(2usize, 7).manhattan(Pos::new(5, 1)), 9);
+
+        let mut c = a;
+        c += Vector::new(1, 1);
+        c -= Vector::new(2, 0);
+        assert_eq!(c, Pos::new(0, -1));
+
+        assert_eq!(
+            Pos::new(1usize, 0).checked_add(Vector::new(-1, 2)),
+            Some(Pos::new(0, 2))
+        );
+        assert_eq!(Pos::new(0usize, 0).checked_add(Vector::new(-1, 0)), None);
+        assert_eq!(Pos::new(3u8, 4).cast::<i32>(), Pos::new(3, 4));
+        assert_eq!(Pos::new(3i64, 4).try_cast::<usize>(), Some(Pos::new(3, 4)));
+        assert_eq!(Pos::new(-3i64, 4).try_cast::<usize>(), None);
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            let step = Vector::<i32>::from(direction);
+            assert_eq!(-step, Vector::from(direction.reverse()));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Vector::<i64>::from(Direction::Up), Vector::new(0, -1));
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, ParseError, Pos, Vector};
+
+/// Row major 2D storage, `Pos { x: 0, y: 0 }` being the top left cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows laid out one after another, `None` if
+    /// `cells` does not split into rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        match (width, cells.len()) {
+            (0, 0) => Some(Self {
+                cells,
+                width,
+                height: 0,
+            }),
+            (0, _) => None,
+            (_, len) if len % width != 0 => None,
+            (_, len) => Some(Self {
+                cells,
+                width,
+                height: len / width,
+            }),
+        }
+    }
+
+    /// Parses one cell per character, one row per line. Trailing empty lines
+    /// are ignored, every other row has to be as wide as the first one.
+    /// `cell` gets the position and character of each cell and returns what
+    /// was expected instead when it is not valid.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut cell: impl FnMut(Pos<usize>, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let grid = input.trim_end_matches('\n');
+        if grid.is_empty() {
+            return Err(ParseError::missing(day, input, "a grid row"));
+        }
+        let mut cells = Vec::with_capacity(grid.len());
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in grid.split('\n').enumerate() {
+            let mut x = 0;
+            for (i, c) in line.char_indices() {
+                let token = &line[i..i + c.len_utf8()];
+                let value =
+                    cell(Pos::new(x, y), c).map_err(|e| ParseError::new(day, input, token, e))?;
+                cells.push(value);
+                x += 1;
+            }
+            if y == 0 {
+                width = x;
+            } else if x != width {
+                return Err(ParseError::new(day, input, line, "rows of equal width"));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    fn index_of(&self, pos: Pos<usize>) -> Option<usize> {
+        self.contains(pos).then(|| pos.x + pos.y * self.width)
+    }
+
+    pub fn get(&self, pos: Pos<usize>) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos<usize>) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces a cell, returning the old value or `None` if `pos` is outside
+    /// of the grid, in which case nothing changes.
+    pub fn set(&mut self, pos: Pos<usize>, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // `chunks` panics on 0, an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Cells of column `x` from top to bottom, empty if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let cells = match x < self.width {
+            true => &self.cells[x..],
+            false => &[],
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos<usize>, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Right, down, left and up neighbours inside the grid.
+    pub fn neighbours_4(&self, pos: Pos<usize>) -> impl Iterator<Item = Pos<usize>> + '_ {
+        self.neighbours(pos, Direction::ALL.map(Vector::from))
+    }
+
+    /// Neighbours inside the grid including diagonals, clockwise from the right.
+    pub fn neighbours_8(&self, pos: Pos<usize>) -> impl Iterator<Item = Pos<usize>> + '_ {
+        let mut steps = [Vector::default(); 8];
+        for (i, direction) in Direction::ALL.into_iter().enumerate() {
+            steps[2 * i] = direction.into();
+            steps[2 * i + 1] = Vector::from(direction) + Vector::from(direction.turn_right());
+        }
+        self.neighbours(pos, steps)
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        pos: Pos<usize>,
+        steps: [Vector<isize>; N],
+    ) -> impl Iterator<Item = Pos<usize>> + '_ {
+        steps
+            .into_iter()
+            .filter_map(move |step| pos.checked_add(step))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Pos<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos<usize>) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{pos:?} is outside of a {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of a {width}x{height} grid"),
+        }
+    }
+}
+
+/// Renders every cell next to each other, rows separated by newlines.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(0, input, |_, c| c.to_digit(10).ok_or("a digit"))
+    }
+
+    #[test]
+    fn grid() {
+        let mut grid = digits("123\n456\n\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.set(Pos::new(0, 1), 0), Some(4));
+        assert_eq!(grid.set(Pos::new(0, 2), 0), None);
+        assert_eq!(grid[Pos::new(0, 1)], 0);
+
+        assert_eq!(grid.row(1), Some(&[0, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.iter().nth(4), Some((Pos::new(1, 1), &5)));
+        assert_eq!(grid.to_string(), "123\n056");
+        assert_eq!(grid.map(|&d| d * 2).to_string(), "246\n01012");
+
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3, 4]).unwrap().height(), 2);
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3]), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, ());
+        let corner = grid.neighbours_4(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours_4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8(Pos::new(1, 1)).count(), 8);
+        let corner = grid.neighbours_8(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Pos::new(1, 0), Pos::new(1, 1), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours_8(Pos::new(2, 2)).count(), 3);
+        assert_eq!(grid.neighbours_8(Pos::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn invalid_grids() {
+        let error = digits("12\n3x").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+        let error = digits("12\n345").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "345")
+        );
+        assert!(digits("").is_err());
+        assert!(digits("\n\n1").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    borrow::Cow,
+    io::BufRead,
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Path standing for stdin wherever an input path is accepted.
+pub const STDIN: &str = "-";
+
+pub fn root() -> PathBuf {
+    PathBuf::from(ROOT_DIR)
+}
+
+pub fn path(day: u32) -> PathBuf {
+    root().join(format!("day{day}")).join("input.txt")
+}
+
+pub fn read(day: u32) -> Result<String, Error> {
+    read_path(&path(day))
+}
+
+/// Reads a file, or stdin if `path` is `-`, normalized.
+pub fn read_path(path: &Path) -> Result<String, Error> {
+    let input = match path == Path::new(STDIN) {
+        true => read_from(std::io::stdin().lock()),
+        false => std::fs::read_to_string(path).map(|input| normalize(&input).into_owned()),
+    };
+    input.map_err(|error| Error::Io(path.to_path_buf(), error))
+}
+
+/// Reads everything left in `reader`, which has to be valid UTF-8, normalized.
+pub fn read_from(mut reader: impl BufRead) -> std::io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(normalize(&input).into_owned())
+}
+
+/// Undoes what editors and other systems do to a saved input: drops a UTF-8
+/// BOM, turns CRLF into LF, strips whitespace at the end of every line and
+/// trailing empty lines, and ends a non empty input with exactly one newline.
+/// Parsers can then rely on `\n` separated lines and `\n\n` separated blocks.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let trimmed = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let trimmed = trimmed.trim_end();
+    let clean = trimmed
+        .split('\n')
+        .all(|line| line.len() == line.trim_end().len());
+    if clean && input.len() == trimmed.len() + 1 && input.ends_with('\n') {
+        return Cow::Borrowed(input);
+    }
+    let mut normalized = String::with_capacity(trimmed.len() + 1);
+    for line in trimmed.split('\n') {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    if trimmed.is_empty() {
+        normalized.clear();
+    }
+    Cow::Owned(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readers() {
+        assert_eq!(read_from(&b"1\n2\n"[..]).unwrap(), "1\n2\n");
+        let reader = std::io::BufReader::new(std::io::Cursor::new("abc"));
+        assert_eq!(read_from(reader).unwrap(), "abc\n");
+        assert_eq!(read_from(&b"1\r\n2"[..]).unwrap(), "1\n2\n");
+        assert!(read_from(&[0xff, 0xfe][..]).is_err());
+        assert!(matches!(
+            read_path(Path::new("no/such/input.txt")),
+            Err(Error::Io(..))
+        ));
+    }
+
+    #[test]
+    fn normalizing() {
+        let expected = "[-] [A]\n 1   2\n\nmove 1\n";
+        for variant in [
+            expected,
+            "[-] [A]\n 1   2\n\nmove 1",
+            "[-] [A]\r\n 1   2\r\n\r\nmove 1\r\n",
+            "\u{feff}[-] [A]\n 1   2\n\nmove 1\n",
+            "[-] [A]  \n 1   2\t\n \nmove 1\n\n\n  ",
+        ] {
+            assert_eq!(normalize(variant), expected, "{variant:?}");
+        }
+        assert!(matches!(normalize(expected), Cow::Borrowed(_)));
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\r\n\n"), "");
+        assert_eq!(normalize("  x"), "  x\n");
+    }
+}
@@ -0,0 +1,69 @@
+use std::{path::Path, process::ExitCode};
+
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod check;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod render;
+pub mod report;
+pub mod simulation;
+pub mod solution;
+
+pub use answer::{Answer, Bitmap};
+pub use error::{Error, ParseError};
+pub use generate::{Generate, Rng};
+pub use geometry::{Direction, Pos, Vector};
+pub use grid::Grid;
+pub use simulation::Simulation;
+pub use solution::{DynSolution, Parsed, Part, Solution};
+
+pub fn print_answer(part: Part, answer: &Answer) {
+    match answer {
+        Answer::Bitmap(_) => println!("part {part}:\n{answer}"),
+        _ => println!("part {part}: {answer}"),
+    }
+}
+
+/// Parses `input` once and solves both parts on it.
+pub fn answers<S: Solution>(input: &str) -> Result<[Answer; 2], ParseError> {
+    let parsed = tracing::debug_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+    Ok(Part::ALL.map(|part| {
+        let _span = tracing::debug_span!("solve", day = S::DAY, %part).entered();
+        S::solve(&parsed, part)
+    }))
+}
+
+/// Reads the input from the path given as the first argument, `-` being
+/// stdin, or from the default input of the day without one.
+fn solve<S: Solution>() -> Result<(), Error> {
+    let input = match std::env::args_os().nth(1) {
+        Some(path) => input::read_path(Path::new(&path))?,
+        None => input::read(S::DAY)?,
+    };
+    let answers = answers::<S>(&input)?;
+    for (part, answer) in Part::ALL.into_iter().zip(&answers) {
+        print_answer(part, answer);
+    }
+    for (part, answer) in Part::ALL.into_iter().zip(answers) {
+        if let Answer::Unsolved(reason) = answer {
+            return Err(Error::Unsolved(S::DAY, part, reason));
+        }
+    }
+    Ok(())
+}
+
+pub fn run<S: Solution>() -> ExitCode {
+    match solve::<S>() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use crate::Grid;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    fn lerp(self, other: Self, numerator: usize, denominator: usize) -> Self {
+        let mix = |a: u8, b: u8| {
+            let (a, b) = (a as usize, b as usize);
+            (a * (denominator - numerator) + b * numerator).div_ceil(denominator) as u8
+        };
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+/// `rrggbb` with an optional leading `#`.
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+        };
+        match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Self::new(r, g, b)),
+            _ => Err(format!("invalid color {s}, expected rrggbb")),
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Colors spread evenly from the lowest to the highest level, with the
+/// levels in between blended linearly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    stops: Vec<Color>,
+}
+
+impl Palette {
+    /// `None` without any colors.
+    pub fn new(stops: Vec<Color>) -> Option<Self> {
+        (!stops.is_empty()).then_some(Self { stops })
+    }
+
+    /// Color of `level` out of `levels`, from 0 to `levels - 1`.
+    pub fn color(&self, level: usize, levels: usize) -> Color {
+        let last = self.stops.len() - 1;
+        if levels <= 1 || last == 0 {
+            return self.stops[0];
+        }
+        let position = level.min(levels - 1) * last;
+        let (i, rest) = (position / (levels - 1), position % (levels - 1));
+        match rest {
+            0 => self.stops[i],
+            rest => self.stops[i].lerp(self.stops[i + 1], rest, levels - 1),
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            stops: vec![Color::new(0x1e, 0x1e, 0x2e), Color::new(0xf5, 0xe0, 0xdc)],
+        }
+    }
+}
+
+/// Comma separated colors, lowest level first.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let stops = s.split(',').map(str::parse).collect::<Result<_, _>>()?;
+        Self::new(stops).ok_or_else(|| "empty palette".to_string())
+    }
+}
+
+/// Colors every cell of `grid` by its level out of `levels`.
+pub fn levels<T>(
+    grid: &Grid<T>,
+    palette: &Palette,
+    levels: usize,
+    level: impl Fn(&T) -> usize,
+) -> Grid<Color> {
+    grid.map(|cell| palette.color(level(cell), levels))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Svg,
+    /// Written without compression, so it needs no dependencies.
+    Png,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Svg => "svg",
+            Self::Png => "png",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            "png" => Ok(Self::Png),
+            _ => Err(format!(
+                "unknown image format {s}, expected ppm, svg or png"
+            )),
+        }
+    }
+}
+
+/// Writes `image` with every cell drawn as a `scale` by `scale` square.
+pub fn write(
+    image: &Grid<Color>,
+    format: Format,
+    scale: usize,
+    out: &mut impl Write,
+) -> std::io::Result<()> {
+    let scale = scale.max(1);
+    match format {
+        Format::Ppm => write_ppm(image, scale, out),
+        Format::Svg => write_svg(image, scale, out),
+        Format::Png => write_png(image, scale, out),
+    }
+}
+
+/// Rows of `image` scaled up, as `r g b` bytes.
+fn scanlines(image: &Grid<Color>, scale: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+    image.rows().flat_map(move |row| {
+        let line = row
+            .iter()
+            .flat_map(|c| [c.r, c.g, c.b].repeat(scale))
+            .collect::<Vec<_>>();
+        std::iter::repeat_n(line, scale)
+    })
+}
+
+fn write_ppm(image: &Grid<Color>, scale: usize, out: &mut impl Write) -> std::io::Result<()> {
+    let (width, height) = (image.width() * scale, image.height() * scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    for line in scanlines(image, scale) {
+        out.write_all(&line)?;
+    }
+    Ok(())
+}
+
+fn write_svg(image: &Grid<Color>, scale: usize, out: &mut impl Write) -> std::io::Result<()> {
+    let (width, height) = (image.width() * scale, image.height() * scale);
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" shape-rendering="crispEdges">"#
+    )?;
+    for (y, row) in image.rows().enumerate() {
+        // One rectangle per run of equally colored cells.
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{}"/>"#,
+                x * scale,
+                y * scale,
+                run.len() * scale,
+                run[0]
+            )?;
+            x += run.len();
+        }
+    }
+    writeln!(out, "</svg>")
+}
+
+fn write_png(image: &Grid<Color>, scale: usize, out: &mut impl Write) -> std::io::Result<()> {
+    let (width, height) = (image.width() * scale, image.height() * scale);
+    let (Ok(png_width), Ok(png_height)) = (u32::try_from(width), u32::try_from(height)) else {
+        return Err(std::io::Error::other("image is too large for a png"));
+    };
+    let mut raw = Vec::with_capacity((width * 3 + 1) * height);
+    for line in scanlines(image, scale) {
+        // No filter.
+        raw.push(0);
+        raw.extend(line);
+    }
+    let mut header = Vec::with_capacity(13);
+    header.extend(png_width.to_be_bytes());
+    header.extend(png_height.to_be_bytes());
+    // 8 bit RGB, default compression, filtering and no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    write_chunk(out, b"IHDR", &header)?;
+    write_chunk(out, b"IDAT", &zlib_stored(&raw))?;
+    write_chunk(out, b"IEND", &[])
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> std::io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// A zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        stream.push(last as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette() {
+        let palette = "000000,ff0000,ffffff".parse::<Palette>().unwrap();
+        assert_eq!(palette.color(0, 5), Color::BLACK);
+        assert_eq!(palette.color(1, 5), Color::new(128, 0, 0));
+        assert_eq!(palette.color(2, 5), Color::new(255, 0, 0));
+        assert_eq!(palette.color(4, 5), Color::WHITE);
+        assert_eq!(palette.color(9, 5), Color::WHITE);
+        assert_eq!(palette.color(0, 1), Color::BLACK);
+        assert_eq!(Color::new(1, 171, 255).to_string(), "#01abff");
+        assert!("".parse::<Palette>().is_err());
+        assert!("12345".parse::<Palette>().is_err());
+        assert!("gg0000".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn formats() {
+        let image = Grid::from_cells(2, vec![Color::BLACK, Color::WHITE]).unwrap();
+
+        let mut ppm = Vec::new();
+        write(&image, Format::Ppm, 2, &mut ppm).unwrap();
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        expected.extend([[0; 6], [255; 6], [0; 6], [255; 6]].concat());
+        assert_eq!(ppm, expected);
+
+        let mut svg = Vec::new();
+        write(&image, Format::Svg, 3, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(r#"width="6" height="3""#));
+        assert!(svg.contains(r##"<rect x="3" y="0" width="3" height="3" fill="#ffffff"/>"##));
+
+        let mut png = Vec::new();
+        write(&image, Format::Png, 1, &mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x01"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        let stream = zlib_stored(&[7; 70_000]);
+        assert_eq!(stream.len(), 2 + 2 * 5 + 70_000 + 4);
+        assert_eq!(stream[2], 0);
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, DynSolution, ParseError, Part};
+
+/// Answer of one part and the time it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    /// The input is parsed once, so both parts share this.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Report {
+    /// A single line JSON object.
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Int(n) => n.to_string(),
+            Answer::Unsolved(_) => "null".to_string(),
+            answer => json_string(&answer.to_string()),
+        };
+        format!(
+            r#"{{"day":{},"part":{},"answer":{answer},"type":"{}","parse_time_ns":{},"solve_time_ns":{}}}"#,
+            self.day,
+            self.part,
+            self.answer.kind(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos()
+        )
+    }
+}
+
+/// Parses `input` once and solves each of `parts` with it.
+pub fn run(day: &dyn DynSolution, input: &str, parts: &[Part]) -> Result<Vec<Report>, ParseError> {
+    let start = Instant::now();
+    let parsed = day.parse(input)?;
+    let parse_time = start.elapsed();
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = parsed.solve(part);
+            Report {
+                day: day.day(),
+                part,
+                answer,
+                parse_time,
+                solve_time: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+/// All reports as a JSON array, one object per line.
+pub fn to_json_array(reports: &[Report]) -> String {
+    let objects = reports
+        .iter()
+        .map(|report| format!("  {}", report.to_json()))
+        .collect::<Vec<_>>();
+    match objects.is_empty() {
+        true => "[]".to_string(),
+        false => format!("[\n{}\n]", objects.join(",\n")),
+    }
+}
+
+/// How running a whole day went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub day: u32,
+    pub title: &'static str,
+    /// Wall time including reading the input, `None` if the day panicked
+    /// before it could be measured.
+    pub elapsed: Option<Duration>,
+    pub reports: Result<Vec<Report>, String>,
+}
+
+impl Outcome {
+    /// First line of the error of a failed day, or the first part that has
+    /// no answer.
+    pub fn failure(&self) -> Option<String> {
+        match &self.reports {
+            Ok(reports) => reports.iter().find_map(|report| match report.answer {
+                Answer::Unsolved(reason) => Some(format!("part {}: {reason}", report.part)),
+                _ => None,
+            }),
+            Err(error) => Some(error.lines().next().unwrap_or("failed").to_string()),
+        }
+    }
+}
+
+/// One row per day with the time of each stage and whether it failed.
+pub fn summary(outcomes: &[Outcome]) -> String {
+    let title_width = outcomes
+        .iter()
+        .map(|outcome| outcome.title.len())
+        .max()
+        .unwrap_or(0)
+        .max("title".len());
+    let mut summary = format!(
+        "{:>3}  {:<title_width$}  {:>10}  {:>10}  {:>10}  {:>10}  status\n",
+        "day", "title", "parse", "part 1", "part 2", "total"
+    );
+    for outcome in outcomes {
+        let reports = outcome.reports.as_deref().unwrap_or_default();
+        let time = |time: Option<Duration>| match time {
+            Some(time) => format!("{time:.1?}"),
+            None => "-".to_string(),
+        };
+        let part = |part| {
+            time(
+                reports
+                    .iter()
+                    .find(|report| report.part == part)
+                    .map(|report| report.solve_time),
+            )
+        };
+        let status = outcome.failure().unwrap_or_else(|| "ok".to_string());
+        writeln!(
+            summary,
+            "{:>3}  {:<title_width$}  {:>10}  {:>10}  {:>10}  {:>10}  {status}",
+            outcome.day,
+            outcome.title,
+            time(reports.first().map(|report| report.parse_time)),
+            part(Part::One),
+            part(Part::Two),
+            time(outcome.elapsed),
+        )
+        .unwrap();
+    }
+    summary
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bitmap;
+
+    fn report(answer: Answer) -> Report {
+        Report {
+            day: 5,
+            part: Part::Two,
+            answer,
+            parse_time: Duration::from_micros(3),
+            solve_time: Duration::from_nanos(42),
+        }
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            report(Answer::Int(-7)).to_json(),
+            r#"{"day":5,"part":2,"answer":-7,"type":"int","parse_time_ns":3000,"solve_time_ns":42}"#
+        );
+        let json = report(Answer::Str("a\"b\\c\u{1}".to_string())).to_json();
+        assert!(json.contains(r#""answer":"a\"b\\c\u0001","type":"str""#));
+        let mut bitmap = Bitmap::new(2, 2);
+        bitmap.set(1, 0, true);
+        let json = report(Answer::Bitmap(bitmap)).to_json();
+        assert!(json.contains(r#""answer":".#\n..","type":"bitmap""#));
+        let json = report(Answer::Unsolved("no path")).to_json();
+        assert!(json.contains(r#""answer":null,"type":"unsolved""#));
+
+        assert_eq!(to_json_array(&[]), "[]");
+        let array = to_json_array(&[report(Answer::Int(1)), report(Answer::Int(2))]);
+        assert_eq!(array.lines().count(), 4);
+        assert!(array.starts_with("[\n  {\"day\":5") && array.ends_with("}\n]"));
+    }
+
+    #[test]
+    fn summary_table() {
+        let outcomes = [
+            Outcome {
+                day: 5,
+                title: "Supply Stacks",
+                elapsed: Some(Duration::from_millis(2)),
+                reports: Ok(vec![report(Answer::Int(1))]),
+            },
+            Outcome {
+                day: 6,
+                title: "Tuning Trouble",
+                elapsed: Some(Duration::from_millis(1)),
+                reports: Ok(vec![report(Answer::Unsolved("no marker"))]),
+            },
+            Outcome {
+                day: 11,
+                title: "Monkey in the Middle",
+                elapsed: Some(Duration::from_millis(30)),
+                reports: Err("error: day 11, line 2\nmore".to_string()),
+            },
+            Outcome {
+                day: 12,
+                title: "Hill Climbing Algorithm",
+                elapsed: None,
+                reports: Err("panicked: oops".to_string()),
+            },
+        ];
+        let table = summary(&outcomes);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("day  title "));
+        assert!(lines[1].starts_with("  5  Supply Stacks         "));
+        assert!(lines[1].ends_with("     3.0µs           -      42.0ns       2.0ms  ok"));
+        assert!(lines[2].ends_with("42.0ns       1.0ms  part 2: no marker"));
+        assert!(lines[3].ends_with("-           -           -      30.0ms  error: day 11, line 2"));
+        assert!(lines[4].ends_with("-           -           -           -  panicked: oops"));
+    }
+}
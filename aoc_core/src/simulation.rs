@@ -0,0 +1,8 @@
+use std::fmt::Display;
+
+/// A puzzle solved one step at a time, so it can be watched while it runs.
+/// Displaying it draws the current state.
+pub trait Simulation: Display {
+    /// Advances by one step, `false` once there is nothing left to do.
+    fn step(&mut self) -> bool;
+}
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use crate::{Answer, Generate, ParseError, Rng};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u32) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_1(input: &Self::Input<'_>) -> Answer;
+    fn part_2(input: &Self::Input<'_>) -> Answer;
+
+    fn solve(input: &Self::Input<'_>, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part_1(input),
+            Part::Two => Self::part_2(input),
+        }
+    }
+}
+
+/// Object safe counterpart of [`Solution`], so days can be stored side by side.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+    fn generate(&self, seed: u64, size: usize) -> String;
+}
+
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Answer;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Answer {
+        let _span = tracing::debug_span!("solve", day = S::DAY, %part).entered();
+        S::solve(&self.0, part)
+    }
+}
+
+impl<S: Solution + Generate + Sync + 'static> DynSolution for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let parsed = tracing::debug_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+        Ok(Box::new(ParsedInput::<S>(parsed)))
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
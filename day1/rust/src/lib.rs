@@ -0,0 +1,59 @@
+//! Day 1, Calorie Counting: the calories carried by each elf, one snack per
+//! line and an empty line between elves.
+
+use std::fmt::Write;
+
+use aoc_core::{error::parse_token, Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 1;
+
+/// Parses into the total calories of every elf, in input order.
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut bags = vec![0];
+        for line in input.lines() {
+            if line.is_empty() {
+                bags.push(0);
+            } else {
+                let val = parse_token::<u32>(DAY, input, line, "a number of calories")?;
+                *bags.last_mut().unwrap() += val;
+            }
+        }
+        Ok(bags)
+    }
+
+    fn part_1(bags: &Self::Input<'_>) -> Answer {
+        let max_bag = bags.iter().max().unwrap();
+        (*max_bag).into()
+    }
+
+    fn part_2(bags: &Self::Input<'_>) -> Answer {
+        let mut bags = bags.clone();
+        bags.sort_unstable();
+        let sum_most_3 = bags.iter().rev().take(3).sum::<u32>();
+        sum_most_3.into()
+    }
+}
+
+impl Generate for Day1 {
+    /// `size` elves carrying 1 to 10 snacks each.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for elf in 0..size.max(1) {
+            if elf != 0 {
+                input.push('\n');
+            }
+            for _ in 0..rng.range(1..=10) {
+                writeln!(input, "{}", rng.range(1000..=60000)).unwrap();
+            }
+        }
+        input
+    }
+}
@@ -0,0 +1,585 @@
+//! Day 10, Cathode-Ray Tube: a CPU with one register driving a CRT.
+
+use std::fmt::{Display, Write};
+
+use aoc_core::{
+    error::parse_token, Answer, Bitmap, Generate, Grid, ParseError, Pos, Rng, Simulation, Solution,
+};
+
+const DAY: u32 = 10;
+
+/// What the CPU runs in one cycle.
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    /// Parses a line of the program into one instruction per cycle it takes.
+    pub fn parse_line(s: &str) -> Result<Vec<Instruction>, ParseError> {
+        match s.split_once(' ') {
+            Some(("addx", num)) => {
+                let n = parse_token(DAY, s, num, "a number")?;
+                Ok(vec![Instruction::Noop, Instruction::Addx(n)])
+            }
+            None if s == "noop" => Ok(vec![Instruction::Noop]),
+            Some((inst, _)) => Err(ParseError::new(DAY, s, inst, "addx or noop")),
+            None => Err(ParseError::new(DAY, s, s, "addx or noop")),
+        }
+    }
+}
+
+/// CPU running one instruction per cycle.
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    reg_x: i32,
+    in_cycle_reg_x: i32,
+    cycles: i32,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cpu {
+    /// Before the first cycle, with the register at 1.
+    pub fn new() -> Self {
+        Self {
+            reg_x: 1,
+            in_cycle_reg_x: 1,
+            cycles: 0,
+        }
+    }
+
+    /// Runs one cycle. The register wraps around instead of overflowing.
+    pub fn execute(&mut self, inst: Instruction) {
+        self.cycles += 1;
+        match inst {
+            Instruction::Noop => self.in_cycle_reg_x = self.reg_x,
+            Instruction::Addx(n) => {
+                self.in_cycle_reg_x = self.reg_x;
+                self.reg_x = self.reg_x.wrapping_add(n)
+            }
+        }
+    }
+
+    /// Value of the register during the last cycle.
+    pub fn in_cycle_reg_x(&self) -> i32 {
+        self.in_cycle_reg_x
+    }
+
+    /// Number of cycles run so far.
+    pub fn cycles(&self) -> i32 {
+        self.cycles
+    }
+}
+
+/// Screen drawing one pixel per cycle, row after row.
+#[derive(Debug, Clone)]
+pub struct Crt {
+    screen: Grid<bool>,
+}
+
+impl Crt {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            screen: Grid::new(width, height, false),
+        }
+    }
+
+    /// Lights the pixel of `cycle`, counted from 1, if the sprite centered
+    /// on `x` covers it. Cycles past the screen draw nothing.
+    pub fn update(&mut self, cycle: i32, x: i32) {
+        let width = self.screen.width();
+        let pixel = usize::try_from(cycle)
+            .ok()
+            .and_then(|cycle| cycle.checked_sub(1));
+        let Some(pixel) = pixel.filter(|_| width != 0) else {
+            return;
+        };
+        let pos = Pos::new(pixel % width, pixel / width);
+        if (x.saturating_sub(1)..=x.saturating_add(1)).contains(&(pos.x as i32)) {
+            self.screen.set(pos, true);
+        }
+    }
+
+    pub fn into_bitmap(self) -> Bitmap {
+        self.screen.into()
+    }
+}
+
+/// Sum of the signal strengths during the 20th, 60th.. 220th cycles, in
+/// `i64` so any register value fits.
+pub fn signal_strength(instructions: &[Instruction]) -> i64 {
+    let mut instructions = instructions.iter().copied();
+    let mut cpu = Cpu::new();
+    for _ in 0..20 {
+        cpu.execute(instructions.next().unwrap_or(Instruction::Noop));
+    }
+    let mut total = i64::from(cpu.in_cycle_reg_x()) * 20;
+    for _ in 0..5 {
+        for _ in 0..40 {
+            cpu.execute(instructions.next().unwrap_or(Instruction::Noop));
+        }
+        total += i64::from(cpu.in_cycle_reg_x()) * i64::from(cpu.cycles());
+    }
+    total
+}
+
+/// Runs the CPU one cycle per step, drawing a pixel of the CRT each time.
+pub struct Screen {
+    cpu: Cpu,
+    crt: Crt,
+    instructions: Vec<Instruction>,
+}
+
+impl Screen {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self {
+            cpu: Cpu::new(),
+            crt: Crt::new(6, 40),
+            instructions,
+        }
+    }
+}
+
+impl Simulation for Screen {
+    fn step(&mut self) -> bool {
+        let Some(&inst) = self.instructions.get(self.cpu.cycles() as usize) else {
+            return false;
+        };
+        self.cpu.execute(inst);
+        self.crt
+            .update(self.cpu.cycles(), self.cpu.in_cycle_reg_x());
+        true
+    }
+}
+
+/// The sprite position for the next cycle above the screen.
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let x = self.cpu.reg_x;
+        writeln!(f, "cycle {}, x = {x}", self.cpu.cycles())?;
+        let sprite = (0..self.crt.screen.width() as i32)
+            .map(|i| {
+                if (x.saturating_sub(1)..=x.saturating_add(1)).contains(&i) {
+                    '#'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        writeln!(f, "{}", sprite.trim_end())?;
+        let screen = self.crt.screen.map(|&lit| if lit { '#' } else { '.' });
+        write!(f, "{screen}")
+    }
+}
+
+/// The CRT screen after running `instructions`.
+pub fn draw(instructions: &[Instruction]) -> Bitmap {
+    let mut screen = Screen::new(instructions.to_vec());
+    while screen.step() {}
+    screen.crt.into_bitmap()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut instructions = Vec::new();
+        for line in input.lines() {
+            instructions.extend(Instruction::parse_line(line).map_err(|e| e.within(input, line))?);
+        }
+        Ok(instructions)
+    }
+
+    fn part_1(instructions: &Self::Input<'_>) -> Answer {
+        signal_strength(instructions).into()
+    }
+
+    fn part_2(instructions: &Self::Input<'_>) -> Answer {
+        draw(instructions).into()
+    }
+}
+
+impl Generate for Day10 {
+    /// `size` instructions, keeping the sprite around the visible columns.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        let mut x = 1;
+        for _ in 0..size {
+            if rng.chance(1, 3) {
+                input.push_str("noop\n");
+            } else {
+                let n = rng.range(-1..=40) - x;
+                x += n;
+                writeln!(input, "addx {n}").unwrap();
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpu() {
+        let input = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+        let instructions = Day10::parse(input).unwrap();
+        let total = signal_strength(&instructions);
+        assert_eq!(total, 13140);
+    }
+
+    #[test]
+    fn screen() {
+        let mut screen = Screen::new(Day10::parse("noop\naddx 3\naddx -5").unwrap());
+        assert!(screen.to_string().starts_with("cycle 0, x = 1\n###\n...."));
+        for _ in 0..3 {
+            assert!(screen.step());
+        }
+        assert!(screen
+            .to_string()
+            .starts_with("cycle 3, x = 4\n   ###\n###."));
+        assert!(screen.step() && screen.step());
+        assert!(!screen.step());
+        assert_eq!(screen.cpu.reg_x, -1);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut cpu = Cpu::new();
+        cpu.execute(Instruction::Addx(i32::MAX - 1));
+        cpu.execute(Instruction::Addx(1));
+        assert_eq!(cpu.in_cycle_reg_x(), i32::MAX);
+
+        let mut crt = Crt::new(1, 2);
+        for cycle in [i32::MIN, 0, 1, 3, i32::MAX] {
+            crt.update(cycle, 1);
+        }
+        crt.update(2, i32::MAX);
+        assert_eq!(crt.screen.cells(), [true, false]);
+        let mut empty = Crt::new(1, 0);
+        empty.update(1, 1);
+
+        let large = Day10::parse("addx 2000000000").unwrap();
+        assert_eq!(Day10::part_1(&large), Answer::Int(720 * 2_000_000_001));
+    }
+
+    #[test]
+    fn crt() {
+        let input = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+        let instructions = Day10::parse(input).unwrap();
+        let bitmap = draw(&instructions);
+        assert_eq!(
+            bitmap.to_string(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+}
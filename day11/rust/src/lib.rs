@@ -0,0 +1,442 @@
+//! Day 11, Monkey in the Middle: monkeys throwing items around by worry
+//! level.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use aoc_core::{error::parse_token, Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 11;
+
+/// Why part 1 has no answer when a worry level outgrows `u64`, and why
+/// neither part has one when the monkey business does not fit in an answer.
+const OVERFLOW: &str = "overflow";
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+impl Operation {
+    /// New worry level modulo `modulo`, which can not overflow.
+    fn apply_mod(&self, old: u64, modulo: u64) -> u64 {
+        let (old, modulo) = (u128::from(old % modulo), u128::from(modulo));
+        let new = match self {
+            Self::Add(n) => old + u128::from(*n),
+            Self::Mul(n) => old * u128::from(*n),
+            Self::Square => old * old,
+        };
+        (new % modulo) as u64
+    }
+
+    /// New worry level, `None` if it does not fit in a `u64`.
+    pub fn checked_apply(&self, old: u64) -> Option<u64> {
+        match self {
+            Self::Add(n) => old.checked_add(*n),
+            Self::Mul(n) => old.checked_mul(*n),
+            Self::Square => old.checked_mul(old),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add(n) => write!(f, "old + {n}"),
+            Self::Mul(n) => write!(f, "old * {n}"),
+            Self::Square => write!(f, "old * old"),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const EXPECTED: &str = "old * old, old * <n> or old + <n>";
+        match s.split_whitespace().collect::<Vec<_>>()[..] {
+            ["old", "*", "old"] => Ok(Self::Square),
+            ["old", "*", n] => Ok(Self::Mul(parse_token(DAY, s, n, "a number")?)),
+            ["old", "+", n] => Ok(Self::Add(parse_token(DAY, s, n, "a number")?)),
+            _ => Err(ParseError::new(DAY, s, s, EXPECTED)),
+        }
+    }
+}
+
+/// A monkey with the items it holds, in the order it inspects them.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    inspected: u64,
+    starting_items: VecDeque<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Monkey {
+    /// Throws to `if_true` the items whose worry level is divisible by
+    /// `divisor`, to `if_false` the others. `None` for a divisor of 0.
+    pub fn new(
+        items: impl IntoIterator<Item = u64>,
+        operation: Operation,
+        divisor: u64,
+        if_true: usize,
+        if_false: usize,
+    ) -> Option<Self> {
+        (divisor != 0).then(|| Self {
+            inspected: 0,
+            starting_items: items.into_iter().collect(),
+            operation,
+            divisor,
+            if_true,
+            if_false,
+        })
+    }
+
+    pub fn items(&self) -> impl ExactSizeIterator<Item = u64> + '_ {
+        self.starting_items.iter().copied()
+    }
+
+    /// Number of items inspected so far.
+    pub fn inspected(&self) -> u64 {
+        self.inspected
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn add_item(&mut self, item: u64) {
+        self.starting_items.push_back(item);
+    }
+
+    /// Monkey an item with worry level `val` is thrown to.
+    pub fn test(&self, val: u64) -> usize {
+        if val.is_multiple_of(self.divisor) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+
+    /// The next item and where it goes, `Some(None)` if its worry level
+    /// overflows.
+    fn inspect(&mut self) -> Option<Option<(u64, usize)>> {
+        let item = self.starting_items.pop_front()?;
+        self.inspected += 1;
+        let new_level = self.operation.checked_apply(item).map(|level| level / 3);
+        Some(new_level.map(|level| (level, self.test(level))))
+    }
+
+    fn inspect_2(&mut self, modulo: u64) -> Option<(u64, usize)> {
+        if let Some(item) = self.starting_items.pop_front() {
+            self.inspected += 1;
+            let new_level = self.operation.apply_mod(item, modulo);
+            let next_monkey = self.test(new_level);
+            Some((new_level, next_monkey))
+        } else {
+            None
+        }
+    }
+}
+
+fn field<'a>(
+    s: &'a str,
+    line: Option<&'a str>,
+    prefix: &'static str,
+) -> Result<&'a str, ParseError> {
+    let line = line.ok_or_else(|| ParseError::missing(DAY, s, prefix.trim_end()))?;
+    line.trim_start()
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(DAY, s, line, prefix.trim_end()))
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let id = field(s, lines.next(), "Monkey ")?;
+        if !id.ends_with(':') {
+            return Err(ParseError::new(DAY, s, id, "a monkey number followed by :"));
+        }
+        let starting_items = field(s, lines.next(), "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_token(DAY, s, item, "a worry level"))
+            .collect::<Result<Vec<u64>, _>>()?;
+        let operation = field(s, lines.next(), "Operation: new = ")?;
+        let operation = operation
+            .parse()
+            .map_err(|e: ParseError| e.within(s, operation))?;
+        let mut last_number = |prefix| {
+            let number = field(s, lines.next(), prefix)?;
+            parse_token::<u64>(DAY, s, number.trim(), "a number")
+        };
+        let divisor = last_number("Test: divisible by ")?;
+        let if_true = last_number("If true: throw to monkey ")? as usize;
+        let if_false = last_number("If false: throw to monkey ")? as usize;
+        Self::new(starting_items, operation, divisor, if_true, if_false)
+            .ok_or_else(|| ParseError::new(DAY, s, s, "a non zero divisor"))
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> Answer {
+    let mut vals = monkeys.iter().map(|m| m.inspected).collect::<Vec<_>>();
+    vals.sort_unstable();
+    let business = match vals[..] {
+        [.., a, b] => a.checked_mul(b).and_then(|n| i64::try_from(n).ok()),
+        _ => Some(0),
+    };
+    business.map_or(Answer::Unsolved(OVERFLOW), Answer::Int)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let blocks = input.split("\n\n").collect::<Vec<_>>();
+        let monkeys = blocks
+            .iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let monkey: Monkey = block
+                    .parse()
+                    .map_err(|e: ParseError| e.within(input, block))?;
+                for target in [monkey.if_true, monkey.if_false] {
+                    if target == i || blocks.len() <= target {
+                        return Err(ParseError::new(
+                            DAY,
+                            input,
+                            block,
+                            "a throw to another monkey",
+                        ));
+                    }
+                }
+                Ok(monkey)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Part 2 keeps worry levels modulo this product.
+        let divisors = monkeys
+            .iter()
+            .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisor));
+        if divisors.is_none() {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                input,
+                "divisors whose product fits in 64 bits",
+            ));
+        }
+        Ok(monkeys)
+    }
+
+    fn part_1(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
+        let traced = tracing::enabled!(tracing::Level::TRACE);
+        for round in 0..20 {
+            let _span = tracing::trace_span!("round", round).entered();
+            for i in 0..monkeys.len() {
+                while let Some(item) = monkeys[i].inspect() {
+                    let Some((new_level, next_monkey)) = item else {
+                        return Answer::Unsolved(OVERFLOW);
+                    };
+                    if traced {
+                        trace_throw(i, next_monkey, new_level);
+                    }
+                    monkeys[next_monkey].add_item(new_level);
+                }
+            }
+        }
+        monkey_business(&monkeys)
+    }
+
+    fn part_2(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
+        let modulo = monkeys.iter().map(|m| m.divisor).product();
+        let traced = tracing::enabled!(tracing::Level::TRACE);
+        for round in 0..10000 {
+            let _span = tracing::trace_span!("round", round).entered();
+            for i in 0..monkeys.len() {
+                while let Some((new_level, next_monkey)) = monkeys[i].inspect_2(modulo) {
+                    if traced {
+                        trace_throw(i, next_monkey, new_level);
+                    }
+                    monkeys[next_monkey].add_item(new_level);
+                }
+            }
+        }
+        monkey_business(&monkeys)
+    }
+}
+
+/// Kept out of line so the disabled event costs the solver loops nothing.
+#[cold]
+#[inline(never)]
+fn trace_throw(from: usize, to: usize, worry: u64) {
+    tracing::trace!(from, to, worry, "throw");
+}
+
+/// Whether an item starting at `monkey` would outgrow `u64` during the 20
+/// rounds of part 1. Items never affect each other, so each one is followed
+/// on its own.
+fn part_1_overflows(monkeys: &[Monkey], monkey: usize, item: u64) -> bool {
+    let (mut monkey, mut item) = (monkey, item);
+    let mut round = 0;
+    while round < 20 {
+        let Some(level) = monkeys[monkey].operation.checked_apply(item) else {
+            return true;
+        };
+        item = level / 3;
+        let next = monkeys[monkey].test(item);
+        if next < monkey {
+            round += 1;
+        }
+        monkey = next;
+    }
+    false
+}
+
+impl Generate for Day11 {
+    /// Two to eight monkeys holding `size` items between them. Divisors are
+    /// distinct primes, so the modulo used in part 2 stays small, and setups
+    /// where worry levels overflow in part 1 are thrown away.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19];
+        loop {
+            let count = rng.range(2..=8) as usize;
+            rng.shuffle(&mut primes);
+            let square = rng.index(count);
+            let mut monkeys = (0..count)
+                .map(|i| {
+                    let operation = match rng.chance(1, 2) {
+                        _ if i == square => Operation::Square,
+                        true => Operation::Mul(rng.range(2..=19) as u64),
+                        false => Operation::Add(rng.range(1..=8) as u64),
+                    };
+                    let mut target = || (i + 1 + rng.index(count - 1)) % count;
+                    let (if_true, if_false) = (target(), target());
+                    Monkey::new([], operation, primes[i], if_true, if_false)
+                        .expect("primes are not 0")
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..size {
+                monkeys[rng.index(count)].add_item(rng.range(50..=99) as u64);
+            }
+            let overflows = (0..count).any(|i| {
+                let items = monkeys[i].starting_items.iter().collect::<HashSet<_>>();
+                items
+                    .into_iter()
+                    .any(|&item| part_1_overflows(&monkeys, i, item))
+            });
+            if overflows {
+                continue;
+            }
+            let mut input = String::new();
+            for (i, monkey) in monkeys.iter().enumerate() {
+                let items = monkey
+                    .starting_items
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>();
+                if i != 0 {
+                    input.push('\n');
+                }
+                writeln!(input, "Monkey {i}:").unwrap();
+                writeln!(input, "  Starting items: {}", items.join(", ")).unwrap();
+                writeln!(input, "  Operation: new = {}", monkey.operation).unwrap();
+                writeln!(input, "  Test: divisible by {}", monkey.divisor).unwrap();
+                writeln!(input, "    If true: throw to monkey {}", monkey.if_true).unwrap();
+                writeln!(input, "    If false: throw to monkey {}", monkey.if_false).unwrap();
+            }
+            return input;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monkeys() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+        let monkeys = Day11::parse(input).unwrap();
+        assert_eq!(Day11::part_1(&monkeys), Answer::Int(10605));
+        assert_eq!(Day11::part_2(&monkeys), Answer::Int(2713310158));
+        assert_eq!(monkeys[0].items().collect::<Vec<_>>(), [79, 98]);
+        assert_eq!(monkeys[2].operation(), Operation::Square);
+        assert_eq!((monkeys[0].test(46), monkeys[0].test(47)), (2, 3));
+
+        assert!(Monkey::new([1], Operation::Add(1), 0, 1, 2).is_none());
+        let mut monkey = Monkey::new([], Operation::Mul(2), 5, 1, 2).unwrap();
+        monkey.add_item(7);
+        assert_eq!(monkey.items().len(), 1);
+        assert_eq!(monkey.inspected(), 0);
+        assert_eq!(Operation::Square.checked_apply(u64::MAX), None);
+    }
+
+    #[test]
+    fn overflows() {
+        let input = "Monkey 0:
+  Starting items: 18446744073709551615
+  Operation: new = old * old
+  Test: divisible by 7
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 3
+  Operation: new = old + 18446744073709551615
+  Test: divisible by 5
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let monkeys = Day11::parse(input).unwrap();
+        assert_eq!(Day11::part_1(&monkeys), Answer::Unsolved(OVERFLOW));
+        assert!(matches!(Day11::part_2(&monkeys), Answer::Int(_)));
+        assert_eq!(Operation::Square.apply_mod(u64::MAX, u64::MAX - 1), 1);
+
+        let huge = input.replace("by 7", "by 18446744073709551557");
+        assert!(Day11::parse(&huge).is_err());
+    }
+}
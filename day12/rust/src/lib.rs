@@ -0,0 +1,334 @@
+//! Day 12, Hill Climbing Algorithm: shortest climbs on a height map.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use aoc_core::{Answer, Direction, Generate, Grid, ParseError, Part, Rng, Simulation, Solution};
+
+const DAY: u32 = 12;
+
+/// Why a part has no answer when E can not be reached.
+const NO_PATH: &str = "no path";
+
+pub type Pos = aoc_core::Pos<usize>;
+
+/// Heights from a to z with a start S at height a and an end E at height z.
+pub struct HightMap {
+    cells: Grid<u8>,
+    start: Pos,
+    end: Pos,
+    lows: Vec<Pos>,
+}
+
+impl FromStr for HightMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut start, mut end) = (None, None);
+        let mut lows = Vec::new();
+        let cells = Grid::parse(DAY, s, |pos, c| match c {
+            'S' => match start.replace(pos) {
+                Some(_) => Err("a single start"),
+                None => {
+                    lows.push(pos);
+                    Ok(b'a')
+                }
+            },
+            'E' => match end.replace(pos) {
+                Some(_) => Err("a single end"),
+                None => Ok(b'z'),
+            },
+            'a'..='z' => {
+                if c == 'a' {
+                    lows.push(pos);
+                }
+                Ok(c as u8)
+            }
+            _ => Err("a height from a to z, S or E"),
+        })?;
+        Ok(Self {
+            cells,
+            start: start.ok_or_else(|| ParseError::missing(DAY, s, "a start S"))?,
+            end: end.ok_or_else(|| ParseError::missing(DAY, s, "an end E"))?,
+            lows,
+        })
+    }
+}
+
+impl HightMap {
+    /// Heights from `b'a'` to `b'z'`, with S and E already replaced.
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.cells
+    }
+
+    /// Fewest steps to E from any cell of height a, `None` if E can not be
+    /// reached.
+    pub fn shortest_path_from_lows(&self) -> Option<u32> {
+        self.lows
+            .iter()
+            .filter_map(|low| self.shortest_path(*low))
+            .min()
+    }
+
+    /// Fewest steps from S to E, `None` if E can not be reached.
+    pub fn shortest_path_from_start(&self) -> Option<u32> {
+        self.shortest_path(self.start)
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn end(&self) -> Pos {
+        self.end
+    }
+
+    /// Fewest steps from `start` to E, `None` if E can not be reached or
+    /// `start` is outside of the map.
+    pub fn shortest_path(&self, start: Pos) -> Option<u32> {
+        if !self.cells.contains(start) {
+            return None;
+        }
+        let _span = tracing::trace_span!("shortest_path", ?start).entered();
+        let traced = tracing::enabled!(tracing::Level::TRACE);
+        let mut visited = HashMap::new();
+        let mut queue = BinaryHeap::new();
+
+        visited.insert(start, 0);
+        queue.push(Reverse((0, start)));
+
+        while let Some(Reverse((path, pos))) = queue.pop() {
+            if traced {
+                trace_pop(pos, path, queue.len());
+            }
+            for new_pos in self.paths(pos) {
+                visited
+                    .entry(new_pos)
+                    .and_modify(|val| *val = (*val).min(path + 1))
+                    .or_insert_with(|| {
+                        queue.push(Reverse((path + 1, new_pos)));
+                        path + 1
+                    });
+            }
+        }
+        visited.get(&self.end).copied()
+    }
+
+    fn hight(&self, pos: Pos) -> u8 {
+        self.cells[pos]
+    }
+
+    fn paths(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.cells
+            .neighbours_4(pos)
+            .filter(move |&new_pos| self.hight(new_pos) <= self.hight(pos) + 1)
+    }
+}
+
+/// Kept out of line so the disabled event costs the search loop nothing.
+#[cold]
+#[inline(never)]
+fn trace_pop(pos: Pos, steps: u32, queued: usize) {
+    tracing::trace!(?pos, steps, queued, "pop");
+}
+
+/// Dijkstra from S, or from every lowest cell for part 2, settling one cell
+/// per step until E is reached.
+pub struct Search {
+    map: HightMap,
+    distances: Grid<Option<u32>>,
+    settled: Grid<bool>,
+    queue: BinaryHeap<Reverse<(u32, Pos)>>,
+    current: Option<(u32, Pos)>,
+    done: bool,
+}
+
+impl Search {
+    pub fn new(map: HightMap, part: Part) -> Self {
+        let (width, height) = (map.cells.width(), map.cells.height());
+        let starts = match part {
+            Part::One => vec![map.start],
+            Part::Two => map.lows.clone(),
+        };
+        let mut distances = Grid::new(width, height, None);
+        let mut queue = BinaryHeap::new();
+        for start in starts {
+            distances[start] = Some(0);
+            queue.push(Reverse((0, start)));
+        }
+        Self {
+            map,
+            distances,
+            settled: Grid::new(width, height, false),
+            queue,
+            current: None,
+            done: false,
+        }
+    }
+}
+
+impl Simulation for Search {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let Some(Reverse((path, pos))) = self.queue.pop() else {
+            self.done = true;
+            return false;
+        };
+        if std::mem::replace(&mut self.settled[pos], true) {
+            // A shorter path to this cell was already settled.
+            return true;
+        }
+        self.current = Some((path, pos));
+        if pos == self.map.end {
+            self.done = true;
+            return true;
+        }
+        for new_pos in self.map.paths(pos) {
+            if self.distances[new_pos].is_none_or(|known| path + 1 < known) {
+                self.distances[new_pos] = Some(path + 1);
+                self.queue.push(Reverse((path + 1, new_pos)));
+            }
+        }
+        true
+    }
+}
+
+/// Settled cells are drawn as #, queued ones as + and the last settled one
+/// as @.
+impl Display for Search {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.current {
+            Some((path, pos)) if pos == self.map.end => writeln!(f, "reached E in {path} steps")?,
+            _ if self.done => writeln!(f, "E can not be reached")?,
+            Some((path, _)) => writeln!(f, "distance {path}, queued {}", self.queue.len())?,
+            None => writeln!(f, "queued {}", self.queue.len())?,
+        }
+        for (pos, &hight) in self.map.cells.iter() {
+            if pos.x == 0 && pos.y != 0 {
+                writeln!(f)?;
+            }
+            let cell = match pos {
+                _ if self.current.is_some_and(|(_, current)| current == pos) => '@',
+                _ if pos == self.map.start => 'S',
+                _ if pos == self.map.end => 'E',
+                _ if self.settled[pos] => '#',
+                _ if self.distances[pos].is_some() => '+',
+                _ => hight as char,
+            };
+            f.write_char(cell)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input<'a> = HightMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Answer {
+        let steps = map.shortest_path_from_start();
+        steps.map_or(Answer::Unsolved(NO_PATH), Answer::from)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Answer {
+        let steps = map.shortest_path_from_lows();
+        steps.map_or(Answer::Unsolved(NO_PATH), Answer::from)
+    }
+}
+
+impl Generate for Day12 {
+    /// A square map `size` cells wide (at least 14) with random heights and a
+    /// climbable trail carved from S to E.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(14);
+        let mut cells = Grid::new(size, size, 'a');
+        for pos in cells.positions() {
+            cells[pos] = rng.lowercase();
+        }
+        // The trail goes from one corner to the opposite one, only ever
+        // climbing by one, so it needs at least 25 steps.
+        let mut steps = [
+            vec![Direction::Right; size - 1],
+            vec![Direction::Down; size - 1],
+        ]
+        .concat();
+        rng.shuffle(&mut steps);
+        let (flip_x, flip_y) = (rng.chance(1, 2), rng.chance(1, 2));
+        for step in &mut steps {
+            if (*step == Direction::Right && flip_x) || (*step == Direction::Down && flip_y) {
+                *step = step.reverse();
+            }
+        }
+        let corner = |flip| if flip { size - 1 } else { 0 };
+        let mut pos = Pos::new(corner(flip_x), corner(flip_y));
+        cells[pos] = 'S';
+        for (i, &step) in steps.iter().enumerate() {
+            pos = pos
+                .checked_add(step.into())
+                .expect("the trail stays on the map");
+            cells[pos] = (b'a' + ((i + 1) * 25 / steps.len()) as u8) as char;
+        }
+        cells[pos] = 'E';
+        format!("{cells}\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let input = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+        let map = input.parse::<HightMap>().unwrap();
+        assert_eq!(map.cells.width(), 8);
+        assert_eq!(map.cells.height(), 5);
+        let min_path = map.shortest_path_from_start();
+        assert_eq!(min_path, Some(31));
+        assert_eq!(map.shortest_path(Pos::new(0, 4)), Some(29));
+        assert_eq!(map.shortest_path(Pos::new(8, 0)), None);
+
+        let walled = "SzE".parse::<HightMap>().unwrap();
+        assert_eq!(walled.shortest_path_from_lows(), None);
+        let walled = Day12::parse("SzE").unwrap();
+        assert_eq!(Day12::part_1(&walled), Answer::Unsolved(NO_PATH));
+        assert_eq!(Day12::part_2(&walled), Answer::Unsolved(NO_PATH));
+    }
+
+    #[test]
+    fn search() {
+        let input = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+        for (part, steps) in [(Part::One, 31), (Part::Two, 29)] {
+            let mut search = Search::new(input.parse().unwrap(), part);
+            assert!(search.to_string().starts_with("queued "));
+            while search.step() {}
+            let frame = search.to_string();
+            assert!(frame.starts_with(&format!("reached E in {steps} steps\n")));
+            let end_row = frame.lines().nth(3).unwrap();
+            assert_eq!(end_row.chars().nth(5), Some('@'));
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Day 2, Rock Paper Scissors: scoring a strategy guide of rounds.
+
+use std::{cmp::Ordering, fmt::Write, str::FromStr};
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 2;
+
+/// How a round has to end, the second column read the part 2 way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl FromStr for GameOutcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(ParseError::new(DAY, s, s, "X, Y or Z")),
+        }
+    }
+}
+
+/// Ordered by what beats what, so the order is not transitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else {
+            match (self, other) {
+                (Self::Rock, Self::Scissors)
+                | (Self::Paper, Self::Rock)
+                | (Self::Scissors, Self::Paper) => Ordering::Greater,
+                _ => Ordering::Less,
+            }
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(ParseError::new(DAY, s, s, "A, B, C, X, Y or Z")),
+        }
+    }
+}
+
+impl Hand {
+    /// Points for picking the hand, win or lose.
+    pub fn score(&self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    /// Hand to play against `self` to get `outcome`.
+    pub fn pivot_hand(&self, outcome: GameOutcome) -> Self {
+        match self {
+            Self::Rock => match outcome {
+                GameOutcome::Win => Self::Paper,
+                GameOutcome::Draw => Self::Rock,
+                GameOutcome::Lose => Self::Scissors,
+            },
+            Self::Paper => match outcome {
+                GameOutcome::Win => Self::Scissors,
+                GameOutcome::Draw => Self::Paper,
+                GameOutcome::Lose => Self::Rock,
+            },
+            Self::Scissors => match outcome {
+                GameOutcome::Win => Self::Rock,
+                GameOutcome::Draw => Self::Scissors,
+                GameOutcome::Lose => Self::Paper,
+            },
+        }
+    }
+
+    /// Total score of playing `self` against `opponent`.
+    pub fn play(&self, opponent: Self) -> u32 {
+        self.score()
+            + match self.cmp(&opponent) {
+                Ordering::Greater => 6,
+                Ordering::Equal => 3,
+                Ordering::Less => 0,
+            }
+    }
+}
+
+/// A line of the guide, where the second column is both a hand and an outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Hand,
+    pub you: Hand,
+    pub outcome: GameOutcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, second) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(DAY, s, "two letters separated by a space"))?;
+        let opponent = opponent
+            .parse::<Hand>()
+            .map_err(|e| e.within(s, opponent))?;
+        let you = second.parse::<Hand>().map_err(|e| e.within(s, second))?;
+        let outcome = second
+            .parse::<GameOutcome>()
+            .map_err(|e| e.within(s, second))?;
+        Ok(Self {
+            opponent,
+            you,
+            outcome,
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| line.parse().map_err(|e: ParseError| e.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(rounds: &Self::Input<'_>) -> Answer {
+        let total_score = rounds
+            .iter()
+            .map(|round| round.you.play(round.opponent))
+            .sum::<u32>();
+        total_score.into()
+    }
+
+    fn part_2(rounds: &Self::Input<'_>) -> Answer {
+        let total_score = rounds
+            .iter()
+            .map(|round| {
+                let you = round.opponent.pivot_hand(round.outcome);
+                you.play(round.opponent)
+            })
+            .sum::<u32>();
+        total_score.into()
+    }
+}
+
+impl Generate for Day2 {
+    /// `size` rounds of strategy guide.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let opponent = rng.choose(&['A', 'B', 'C']);
+            let response = rng.choose(&['X', 'Y', 'Z']);
+            writeln!(input, "{opponent} {response}").unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn test_hands() {
+        let rock = Hand::Rock;
+        let paper = Hand::Paper;
+        let scissors = Hand::Scissors;
+
+        assert_eq!(Ok(rock), Hand::from_str("A"));
+        assert_eq!(Ok(rock), Hand::from_str("X"));
+        assert_eq!(Ok(paper), Hand::from_str("B"));
+        assert_eq!(Ok(paper), Hand::from_str("Y"));
+        assert_eq!(Ok(scissors), Hand::from_str("C"));
+        assert_eq!(Ok(scissors), Hand::from_str("Z"));
+
+        assert_eq!(rock.play(rock), 1 + 3);
+        assert_eq!(rock.play(paper), 1 + 0);
+        assert_eq!(rock.play(scissors), 1 + 6);
+
+        assert_eq!(paper.play(rock), 2 + 6);
+        assert_eq!(paper.play(paper), 2 + 3);
+        assert_eq!(paper.play(scissors), 2 + 0);
+
+        assert_eq!(scissors.play(rock), 3 + 0);
+        assert_eq!(scissors.play(paper), 3 + 6);
+        assert_eq!(scissors.play(scissors), 3 + 3);
+    }
+
+    #[test]
+    fn invalid_rounds() {
+        let input = "A Y\nB\nC Z";
+        let error = Day2::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let input = "A Y\nB X\nC W";
+        let error = Day2::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.text, "W");
+    }
+}
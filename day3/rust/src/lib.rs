@@ -0,0 +1,144 @@
+//! Day 3, Rucksack Reorganization: items found in both compartments of a
+//! rucksack and badges shared by groups of three elves.
+
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 3;
+
+/// 1 to 26 for `a` to `z`, 27 to 52 for `A` to `Z`, `None` for anything else.
+pub fn priority(c: u8) -> Option<u32> {
+    match c {
+        b'a'..=b'z' => Some((c - b'a' + 1) as u32),
+        b'A'..=b'Z' => Some((c - b'A' + 27) as u32),
+        _ => None,
+    }
+}
+
+/// Item in both halves of `rucksack`, the first one if there are several.
+pub fn misplaced_item(rucksack: &str) -> Option<u8> {
+    let (left, right) = rucksack.split_at_checked(rucksack.len() / 2)?;
+    let right = HashSet::<char>::from_iter(right.chars());
+    left.chars().find(|c| right.contains(c)).map(|c| c as u8)
+}
+
+/// Item carried by every rucksack of `group`, `None` for an empty group.
+pub fn badge(group: &[&str]) -> Option<u8> {
+    let (first, rest) = group.split_first()?;
+    let rest = rest
+        .iter()
+        .map(|rucksack| HashSet::<char>::from_iter(rucksack.chars()))
+        .collect::<Vec<_>>();
+    first
+        .chars()
+        .find(|c| rest.iter().all(|items| items.contains(c)))
+        .map(|c| c as u8)
+}
+
+/// Parses into the rucksacks, each an even number of item letters.
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let invalid = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic());
+                if let Some((i, c)) = invalid {
+                    let item = &line[i..i + c.len_utf8()];
+                    Err(ParseError::new(DAY, input, item, "an item letter"))
+                } else if line.len() % 2 != 0 {
+                    Err(ParseError::new(DAY, input, line, "an even number of items"))
+                } else {
+                    Ok(line)
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Answer {
+        let total = lines
+            .iter()
+            .filter_map(|line| misplaced_item(line))
+            .filter_map(priority)
+            .sum::<u32>();
+        total.into()
+    }
+
+    fn part_2(lines: &Self::Input<'_>) -> Answer {
+        let total = lines
+            .chunks(3)
+            .filter_map(badge)
+            .filter_map(priority)
+            .sum::<u32>();
+        total.into()
+    }
+}
+
+impl Generate for Day3 {
+    /// `size` rucksacks, rounded up to whole groups of three elves.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.div_ceil(3).max(1) {
+            let mut letters = (b'a'..=b'z')
+                .chain(b'A'..=b'Z')
+                .map(char::from)
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut letters);
+            let (badge, others) = letters.split_first().unwrap();
+            // Every elf draws from its own letters, so the badge is the only
+            // item the whole group shares.
+            for own in others.chunks(others.len() / 3).take(3) {
+                let (shared, own) = own.split_first().unwrap();
+                let shared = if rng.chance(1, 4) { badge } else { shared };
+                let (left, right) = own.split_at(own.len() / 2);
+                let half = rng.range(2..=16) as usize;
+                let mut first = vec![*shared];
+                if shared != badge {
+                    first.push(*badge);
+                }
+                while first.len() < half {
+                    first.push(*rng.choose(left));
+                }
+                let mut second = vec![*shared];
+                while second.len() < half {
+                    second.push(*rng.choose(right));
+                }
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+                input.extend(first);
+                input.extend(second);
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items() {
+        assert_eq!(misplaced_item("vJrwpWtwJgWrhcsFMMfFFhFp"), Some(b'p'));
+        assert_eq!(misplaced_item("abcd"), None);
+        assert_eq!(misplaced_item(""), None);
+        let group = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ];
+        assert_eq!(badge(&group), Some(b'r'));
+        assert_eq!(badge(&[]), None);
+        assert_eq!(priority(b'p'), Some(16));
+        assert_eq!(priority(b'L'), Some(38));
+        assert_eq!(priority(b'-'), None);
+    }
+}
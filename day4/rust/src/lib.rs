@@ -0,0 +1,158 @@
+//! Day 4, Camp Cleanup: pairs of section ranges.
+
+use std::{cmp::Ordering, fmt::Write, str::FromStr};
+
+use aoc_core::{error::parse_token, Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 4;
+
+/// Non-empty range of sections, both ends included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range(std::ops::RangeInclusive<u32>);
+
+impl Range {
+    /// `None` if `end` comes before `start`.
+    pub fn new(start: u32, end: u32) -> Option<Self> {
+        (start <= end).then_some(Self(start..=end))
+    }
+
+    /// Whether every section of `other` is in this range.
+    pub fn fully_contains(&self, other: &Range) -> bool {
+        self.0.contains(other.0.start()) && self.0.contains(other.0.end())
+    }
+
+    /// Whether the ranges share a section.
+    pub fn overlap(&self, other: &Range) -> bool {
+        match self.0.start().cmp(other.0.start()) {
+            Ordering::Equal => true,
+            Ordering::Less => other.0.start() <= self.0.end(),
+            Ordering::Greater => self.0.start() <= other.0.end(),
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::missing(DAY, s, "a range like 2-4"))?;
+        let left = parse_token::<u32>(DAY, s, left, "a section number")?;
+        let right = parse_token::<u32>(DAY, s, right, "a section number")?;
+        Self::new(left, right)
+            .ok_or_else(|| ParseError::new(DAY, s, s, "a range with start <= end"))
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input<'a> = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (range1, range2) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::missing(DAY, line, "two ranges like 2-4,6-8"))
+                    .map_err(|e| e.within(input, line))?;
+                let range1 = range1
+                    .parse::<Range>()
+                    .map_err(|e| e.within(input, range1))?;
+                let range2 = range2
+                    .parse::<Range>()
+                    .map_err(|e| e.within(input, range2))?;
+                Ok((range1, range2))
+            })
+            .collect()
+    }
+
+    fn part_1(pairs: &Self::Input<'_>) -> Answer {
+        let total = pairs
+            .iter()
+            .map(|(range1, range2)| {
+                u32::from(range1.fully_contains(range2) || range2.fully_contains(range1))
+            })
+            .sum::<u32>();
+        total.into()
+    }
+
+    fn part_2(pairs: &Self::Input<'_>) -> Answer {
+        let total = pairs
+            .iter()
+            .map(|(range1, range2)| u32::from(range1.overlap(range2)))
+            .sum::<u32>();
+        total.into()
+    }
+}
+
+impl Generate for Day4 {
+    /// `size` pairs of section assignments between 1 and 99.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut range = || {
+                let start = rng.range(1..=99);
+                (start, rng.range(start..=99))
+            };
+            let ((a, b), (c, d)) = (range(), range());
+            writeln!(input, "{a}-{b},{c}-{d}").unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check;
+
+    use super::*;
+
+    type Pair = (u32, u32, u32, u32);
+
+    fn sections(rng: &mut Rng, _size: usize) -> Pair {
+        let mut section = || rng.range(0..=12) as u32;
+        (section(), section(), section(), section())
+    }
+
+    fn ranges(&(a, b, c, d): &Pair) -> (Range, Range) {
+        (Range(a.min(b)..=a.max(b)), Range(c.min(d)..=c.max(d)))
+    }
+
+    #[test]
+    fn overlap_matches_oracle() {
+        check::differential(
+            "Range::overlap",
+            sections,
+            |pair| {
+                let (left, right) = ranges(pair);
+                left.0.clone().any(|section| right.0.contains(&section))
+            },
+            |pair| {
+                let (left, right) = ranges(pair);
+                left.overlap(&right)
+            },
+        );
+    }
+
+    #[test]
+    fn fully_contains_matches_oracle() {
+        check::differential(
+            "Range::fully_contains",
+            sections,
+            |pair| {
+                let (left, right) = ranges(pair);
+                right.0.clone().all(|section| left.0.contains(&section))
+            },
+            |pair| {
+                let (left, right) = ranges(pair);
+                left.fully_contains(&right)
+            },
+        );
+    }
+}
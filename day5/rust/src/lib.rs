@@ -0,0 +1,474 @@
+//! Day 5, Supply Stacks: crates moved between stacks by a crane.
+
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use aoc_core::{error::parse_token, Answer, Generate, ParseError, Part, Rng, Simulation, Solution};
+
+const DAY: u32 = 5;
+
+/// A crate labelled with an ASCII letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crate(char);
+
+impl Crate {
+    /// `None` unless `label` is an ASCII letter.
+    pub fn new(label: char) -> Option<Self> {
+        label.is_ascii_alphabetic().then_some(Self(label))
+    }
+
+    pub fn label(self) -> char {
+        self.0
+    }
+}
+
+impl From<Crate> for char {
+    fn from(c: Crate) -> Self {
+        c.0
+    }
+}
+
+impl FromStr for Crate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [b'[', c, b']'] if c.is_ascii_alphabetic() => Ok(Self(*c as char)),
+            _ => Err(ParseError::new(DAY, s, s, "a crate like [A] or [-]")),
+        }
+    }
+}
+
+/// Moves `num` crates from one stack to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    /// Stacks are numbered from 1, as in the input, so `None` for stack 0.
+    pub fn new(num: usize, from: usize, to: usize) -> Option<Self> {
+        Some(Self {
+            num,
+            from: from.checked_sub(1)?,
+            to: to.checked_sub(1)?,
+        })
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = s.split_whitespace();
+        let mut next = |expected| {
+            items
+                .next()
+                .ok_or_else(|| ParseError::missing(DAY, s, expected))
+        };
+        let keyword = |token: &str, keyword: &'static str| {
+            if token == keyword {
+                Ok(())
+            } else {
+                Err(ParseError::new(DAY, s, token, keyword))
+            }
+        };
+        let stack = |token: &str| {
+            parse_token::<usize>(DAY, s, token, "a stack number")?
+                .checked_sub(1)
+                .ok_or_else(|| ParseError::new(DAY, s, token, "a stack number"))
+        };
+        keyword(next("move")?, "move")?;
+        let num = parse_token(DAY, s, next("a number of crates")?, "a number of crates")?;
+        keyword(next("from")?, "from")?;
+        let from = stack(next("a stack number")?)?;
+        keyword(next("to")?, "to")?;
+        let to = stack(next("a stack number")?)?;
+        if let Some(token) = items.next() {
+            return Err(ParseError::new(DAY, s, token, "end of line"));
+        }
+        Ok(Self { num, from, to })
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.num,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// Stacks of crates, bottom crate first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stacks {
+    stacks: Vec<Vec<Crate>>,
+}
+
+impl Stacks {
+    /// Applies `m` with the crane of `part`: the one of part 1 moves crates
+    /// one at a time, the one of part 2 all at once. Returns `false` and
+    /// leaves the stacks untouched if `m` needs a missing stack or crate.
+    pub fn apply(&mut self, m: &Move, part: Part) -> bool {
+        let len = self.stacks.len();
+        if m.from >= len || m.to >= len || self.stacks[m.from].len() < m.num {
+            tracing::debug!(%m, "impossible move");
+            return false;
+        }
+        tracing::trace!(%m, %part, "move");
+        match part {
+            Part::One => self.apply_move(m),
+            Part::Two => self.apply_move_2(m),
+        }
+        true
+    }
+
+    fn apply_move(&mut self, m: &Move) {
+        for _ in 0..m.num {
+            let c = self.stacks[m.from].pop().unwrap();
+            self.stacks[m.to].push(c)
+        }
+    }
+
+    fn apply_move_2(&mut self, m: &Move) {
+        if m.from == m.to {
+            return;
+        }
+        if m.from < m.to {
+            let (from, to) = self.stacks.split_at_mut(m.to);
+            let from = &mut from[m.from];
+            let to = &mut to[0];
+            to.extend_from_slice(&from[(from.len() - m.num)..]);
+            from.resize(from.len() - m.num, Crate('-'));
+        } else {
+            let (to, from) = self.stacks.split_at_mut(m.from);
+            let to = &mut to[m.to];
+            let from = &mut from[0];
+            to.extend_from_slice(&from[(from.len() - m.num)..]);
+            from.resize(from.len() - m.num, Crate('-'));
+        }
+    }
+
+    fn top_row(&self) -> Vec<Crate> {
+        self.stacks
+            .iter()
+            .filter_map(|s| s.last().copied())
+            .collect()
+    }
+
+    /// Labels of the top crate of every non-empty stack.
+    pub fn top_row_string(&self) -> String {
+        self.top_row().into_iter().map(char::from).collect()
+    }
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().rev();
+        let cols = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(DAY, s, "stack numbers"))?;
+        // Stacks are numbered 1, 2, 3.. so a huge number can not allocate
+        // stacks the input never mentions.
+        let mut cols_num = 0;
+        for col in cols.split_whitespace() {
+            cols_num += 1;
+            if parse_token::<usize>(DAY, s, col, "a stack number")? != cols_num {
+                return Err(ParseError::new(DAY, s, col, "consecutive stack numbers"));
+            }
+        }
+        if cols_num == 0 {
+            return Err(ParseError::new(DAY, s, cols, "stack numbers"));
+        }
+        let mut stacks = Self {
+            stacks: vec![vec![]; cols_num],
+        };
+        for line in lines {
+            for (i, c) in line.split_whitespace().enumerate() {
+                if i == cols_num {
+                    return Err(ParseError::new(DAY, s, c, "end of line"));
+                }
+                if c != "[-]" {
+                    let c = c.parse::<Crate>().map_err(|e| e.within(s, c))?;
+                    stacks.stacks[i].push(c);
+                }
+            }
+        }
+        Ok(stacks)
+    }
+}
+
+/// Draws the stacks the way the input does, with `[-]` for empty slots, so
+/// the output parses back into the same stacks.
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c.0),
+                    None => "[-]".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        let numbers = (1..=self.stacks.len())
+            .map(|i| format!(" {i} "))
+            .collect::<Vec<_>>();
+        write!(f, "{}", numbers.join(" ").trim_end())
+    }
+}
+
+/// Moves crates one instruction per step, with the crane of `part`.
+pub struct Crane {
+    stacks: Stacks,
+    moves: Vec<Move>,
+    done: usize,
+    part: Part,
+}
+
+impl Crane {
+    pub fn new((stacks, moves): (Stacks, Vec<Move>), part: Part) -> Self {
+        Self {
+            stacks,
+            moves,
+            done: 0,
+            part,
+        }
+    }
+}
+
+impl Simulation for Crane {
+    fn step(&mut self) -> bool {
+        let Some(m) = self.moves.get(self.done) else {
+            return false;
+        };
+        self.stacks.apply(m, self.part);
+        self.done += 1;
+        true
+    }
+}
+
+impl Display for Crane {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.done.checked_sub(1) {
+            Some(last) => writeln!(
+                f,
+                "{} ({}/{})",
+                self.moves[last],
+                self.done,
+                self.moves.len()
+            )?,
+            None => writeln!(f, "start (0/{})", self.moves.len())?,
+        }
+        writeln!(f, "top: {}", self.stacks.top_row_string())?;
+        write!(f, "{}", self.stacks)
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input<'a> = (Stacks, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (stacks, moves) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::missing(DAY, input, "an empty line between stacks and moves")
+        })?;
+        let stacks: Stacks = stacks
+            .parse()
+            .map_err(|e: ParseError| e.within(input, stacks))?;
+        let mut heights = stacks.stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        let moves = moves
+            .lines()
+            .map(|line| {
+                let m: Move = line
+                    .parse()
+                    .map_err(|e: ParseError| e.within(input, line))?;
+                if heights.len() <= m.from.max(m.to) {
+                    return Err(ParseError::new(
+                        DAY,
+                        input,
+                        line,
+                        "a move between existing stacks",
+                    ));
+                }
+                if heights[m.from] < m.num {
+                    return Err(ParseError::new(
+                        DAY,
+                        input,
+                        line,
+                        "a move of available crates",
+                    ));
+                }
+                heights[m.from] -= m.num;
+                heights[m.to] += m.num;
+                Ok(m)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((stacks, moves))
+    }
+
+    fn part_1((stacks, moves): &Self::Input<'_>) -> Answer {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            stacks.apply(m, Part::One);
+        }
+        stacks.top_row_string().into()
+    }
+
+    fn part_2((stacks, moves): &Self::Input<'_>) -> Answer {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            stacks.apply(m, Part::Two);
+        }
+        stacks.top_row_string().into()
+    }
+}
+
+impl Generate for Day5 {
+    /// Nine stacks of up to eight crates followed by `size` moves, each only
+    /// taking crates that are there at that point.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const STACKS: usize = 9;
+        let mut heights = (0..STACKS)
+            .map(|_| rng.range(1..=8) as usize)
+            .collect::<Vec<_>>();
+        let mut input = String::new();
+        for level in (0..*heights.iter().max().unwrap()).rev() {
+            let row = heights
+                .iter()
+                .map(|&height| match height > level {
+                    true => format!("[{}]", rng.lowercase().to_ascii_uppercase()),
+                    false => "[-]".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(input, "{}", row.join(" ")).unwrap();
+        }
+        let numbers = (1..=STACKS).map(|i| format!(" {i} ")).collect::<Vec<_>>();
+        writeln!(input, "{}\n", numbers.join(" ")).unwrap();
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.index(STACKS);
+                if heights[from] != 0 {
+                    break from;
+                }
+            };
+            let to = rng.index(STACKS);
+            let num = rng.range(1..=heights[from] as i64) as usize;
+            heights[from] -= num;
+            heights[to] += num;
+            writeln!(input, "move {num} from {} to {}", from + 1, to + 1).unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacks() {
+        let input = "[-] [D] [-]
+[N] [C] [-]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        let input = Day5::parse(input).unwrap();
+        assert_eq!(Day5::part_1(&input), Answer::Str("CMZ".to_string()));
+        assert_eq!(Day5::part_2(&input), Answer::Str("MCD".to_string()));
+    }
+
+    #[test]
+    fn crane() {
+        let input = "[-] [D] [-]\n[N] [C] [-]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1";
+        let mut crane = Crane::new(Day5::parse(input).unwrap(), Part::One);
+        assert_eq!(
+            crane.to_string(),
+            "start (0/1)\ntop: NDP\n[-] [D] [-]\n[N] [C] [-]\n[Z] [M] [P]\n 1   2   3"
+        );
+        assert!(crane.step());
+        assert!(!crane.step());
+        assert!(crane
+            .to_string()
+            .starts_with("move 1 from 2 to 1 (1/1)\ntop: DCP\n[D] [-] [-]\n"));
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove x from 1 to 2";
+        let error = Day5::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (5, 6, "x"));
+
+        let input = "[A] [B]\n 1   2\n\nmove 2 from 1 to 2";
+        let error = Day5::parse(input).err().unwrap();
+        assert_eq!(error.line, 4);
+
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1 to";
+        let error = Day5::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 17, ""));
+    }
+
+    #[test]
+    fn applying() {
+        let mut stacks = "[A] [B]\n 1   2".parse::<Stacks>().unwrap();
+        assert!(!stacks.apply(&Move::new(2, 1, 2).unwrap(), Part::One));
+        assert!(!stacks.apply(&Move::new(1, 1, 3).unwrap(), Part::Two));
+        assert_eq!(stacks.top_row_string(), "AB");
+        assert!(stacks.apply(&Move::new(1, 1, 2).unwrap(), Part::Two));
+        assert_eq!(stacks.top_row_string(), "A");
+        assert!(Move::new(1, 0, 2).is_none());
+        assert_eq!(Crate::new('x').map(Crate::label), Some('x'));
+        assert!(Crate::new('1').is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in [
+            "[-] [D] [-]\n[N] [C] [-]\n[Z] [M] [P]\n 1   2   3",
+            " 1   2",
+        ] {
+            let stacks = input.parse::<Stacks>().unwrap();
+            assert_eq!(stacks.to_string(), input);
+            assert_eq!(stacks.to_string().parse::<Stacks>().unwrap(), stacks);
+        }
+        let input = Day5::generate(&mut Rng::new(5), 20);
+        let (stacks, moves) = Day5::parse(&input).unwrap();
+        let mut crane = Crane::new((stacks.clone(), moves), Part::Two);
+        while crane.step() {}
+        for stacks in [stacks, crane.stacks] {
+            assert_eq!(stacks.to_string().parse::<Stacks>().unwrap(), stacks);
+        }
+    }
+
+    #[test]
+    fn invalid_stack_numbers() {
+        let error = "[A]\n 99999999999".parse::<Stacks>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = "[A] [B]\n 1   3".parse::<Stacks>().err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 6, "3"));
+
+        assert!("[A]\n ".parse::<Stacks>().is_err());
+    }
+}
@@ -0,0 +1,253 @@
+//! Day 6, Tuning Trouble: markers of distinct letters in a signal.
+
+use std::collections::HashMap;
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 6;
+
+/// Why a part has no answer when the signal has no marker.
+const NO_MARKER: &str = "no marker";
+
+/// Multiset of bytes telling whether any of them repeats.
+#[derive(Debug, Default)]
+pub struct UniqueSet {
+    map: HashMap<u8, u8>,
+    repeats: u32,
+}
+
+impl UniqueSet {
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            repeats: 0,
+        }
+    }
+
+    pub fn add(&mut self, item: u8) {
+        let count = self.map.entry(item).or_insert(0);
+        if *count != 0 {
+            self.repeats += 1;
+        }
+        *count += 1;
+    }
+
+    /// Removes one `item`, doing nothing if there is none.
+    pub fn remove(&mut self, item: u8) {
+        let Some(count) = self.map.get_mut(&item) else {
+            return;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.map.remove(&item);
+        } else {
+            self.repeats -= 1;
+        }
+    }
+
+    /// Whether no byte was added more times than removed.
+    pub fn unique(&self) -> bool {
+        self.repeats == 0
+    }
+}
+
+/// Number of bytes up to and including the first `size` distinct ones, `None`
+/// if there are none.
+pub fn find_marker(bytes: &[u8], size: usize) -> Option<usize> {
+    let mut unique_set = UniqueSet::new();
+    for &byte in bytes.get(..size)? {
+        unique_set.add(byte);
+    }
+    for i in size..=bytes.len() {
+        if unique_set.unique() {
+            return Some(i);
+        }
+        if let Some(&byte) = bytes.get(i) {
+            unique_set.add(byte);
+            unique_set.remove(bytes[i - size]);
+        }
+    }
+    None
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input<'a> = &'a [u8];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let signal = input.strip_suffix('\n').unwrap_or(input);
+        if let Some((i, c)) = signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            let c = &signal[i..i + c.len_utf8()];
+            return Err(ParseError::new(DAY, input, c, "a lowercase letter"));
+        }
+        Ok(signal.as_bytes())
+    }
+
+    fn part_1(bytes: &Self::Input<'_>) -> Answer {
+        find_marker(bytes, 4).map_or(Answer::Unsolved(NO_MARKER), Answer::from)
+    }
+
+    fn part_2(bytes: &Self::Input<'_>) -> Answer {
+        find_marker(bytes, 14).map_or(Answer::Unsolved(NO_MARKER), Answer::from)
+    }
+}
+
+impl Generate for Day6 {
+    /// A signal of `size` letters (at least 14) with the start of message
+    /// marker close to its end.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(14);
+        let mut letters = (b'a'..=b'z').map(char::from).collect::<Vec<_>>();
+        let mut input = String::new();
+        // Too few distinct letters for a start of packet marker, then too few
+        // for a start of message marker.
+        rng.shuffle(&mut letters);
+        for _ in 0..(size - 14) / 2 {
+            input.push(*rng.choose(&letters[..3]));
+        }
+        rng.shuffle(&mut letters);
+        while input.len() < size - 14 {
+            input.push(*rng.choose(&letters[..13]));
+        }
+        rng.shuffle(&mut letters);
+        input.extend(&letters[..14]);
+        input.push('\n');
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::check;
+
+    use super::*;
+
+    /// A short signal over a small alphabet, so repeats are common, and a
+    /// window of 1 to 6 letters.
+    fn signal(rng: &mut Rng, size: usize) -> (Vec<u8>, usize) {
+        let bytes = (0..size).map(|_| b'a' + rng.below(5) as u8).collect();
+        (bytes, rng.below(6) as usize)
+    }
+
+    fn distinct(window: &[u8]) -> bool {
+        window.iter().collect::<HashSet<_>>().len() == window.len()
+    }
+
+    #[test]
+    fn unique_set() {
+        let mut us = UniqueSet::new();
+
+        us.add(0);
+        us.add(1);
+        us.add(2);
+
+        assert!(us.unique());
+
+        us.add(0);
+        assert!(!us.unique());
+        us.add(1);
+        assert!(!us.unique());
+        us.add(2);
+        assert!(!us.unique());
+
+        us.remove(0);
+        assert!(!us.unique());
+        us.remove(1);
+        assert!(!us.unique());
+        us.remove(2);
+        assert!(us.unique());
+
+        us.add(1);
+        us.remove(1);
+        assert!(us.unique());
+
+        us.add(3);
+        us.remove(3);
+        assert!(us.unique());
+    }
+
+    #[test]
+    fn unique_set_testcases() {
+        fn test_ans(bytes: &[u8], ans: usize) {
+            let mut us = UniqueSet::new();
+            for &byte in &bytes[..4] {
+                us.add(byte);
+            }
+            let mut res = 0;
+            for i in 4..bytes.len() {
+                if us.unique() {
+                    res = i;
+                    break;
+                }
+                us.add(bytes[i]);
+                us.remove(bytes[i - 4]);
+            }
+            assert_eq!(res, ans)
+        }
+        let bytes = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes();
+        test_ans(bytes, 7);
+        let bytes = "bvwbjplbgvbhsrlpgdmjqwftvncz".as_bytes();
+        test_ans(bytes, 5);
+        let bytes = "nppdvjthqldpwncqszvftbrmjlhg".as_bytes();
+        test_ans(bytes, 6);
+        let bytes = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_bytes();
+        test_ans(bytes, 10);
+        let bytes = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".as_bytes();
+        test_ans(bytes, 11);
+    }
+
+    #[test]
+    fn marker_at_the_end() {
+        assert_eq!(find_marker(b"abcd", 4), Some(4));
+        assert_eq!(find_marker(b"aaabcd", 4), Some(6));
+        assert_eq!(find_marker(b"abc", 4), None);
+        assert_eq!(find_marker(b"abcabc", 4), None);
+        let repeating = Day6::parse("abcabcabcabcabcabc").unwrap();
+        assert_eq!(Day6::part_1(&repeating), Answer::Unsolved(NO_MARKER));
+        assert_eq!(Day6::part_2(&repeating), Answer::Unsolved(NO_MARKER));
+        let short = Day6::parse("abcd\n").unwrap();
+        assert_eq!(Day6::part_1(&short), Answer::Int(4));
+        assert_eq!(Day6::part_2(&short), Answer::Unsolved(NO_MARKER));
+    }
+
+    #[test]
+    fn unique_set_matches_oracle() {
+        check::differential(
+            "UniqueSet",
+            signal,
+            |(bytes, extra)| bytes.windows(extra + 1).map(distinct).collect::<Vec<_>>(),
+            |(bytes, extra)| {
+                let window = extra + 1;
+                let mut set = UniqueSet::new();
+                let mut unique = Vec::new();
+                for (i, &byte) in bytes.iter().enumerate() {
+                    set.add(byte);
+                    if i >= window {
+                        set.remove(bytes[i - window]);
+                    }
+                    if i + 1 >= window {
+                        unique.push(set.unique());
+                    }
+                }
+                unique
+            },
+        );
+    }
+
+    #[test]
+    fn find_marker_matches_oracle() {
+        let oracle = |(bytes, extra): &(Vec<u8>, usize)| {
+            let window = extra + 1;
+            bytes.windows(window).position(distinct).map(|i| i + window)
+        };
+        check::differential("find_marker", signal, oracle, |(bytes, extra)| {
+            find_marker(bytes, extra + 1)
+        });
+    }
+}
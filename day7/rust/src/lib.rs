@@ -0,0 +1,485 @@
+//! Day 7, No Space Left On Device: a file system rebuilt from `cd` and
+//! `ls` output.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    iter::Peekable,
+};
+
+use aoc_core::{error::parse_token, Answer, Generate, ParseError, Rng, Solution};
+
+const DAY: u32 = 7;
+const DISK_SIZE: usize = 70_000_000;
+const NEEDED_SPACE: usize = 30_000_000;
+
+enum Size {
+    DirSize(Vec<usize>),
+    FileSize(usize),
+}
+
+#[derive(Debug)]
+struct File<'a> {
+    name: &'a str,
+    size: usize,
+}
+
+#[derive(Debug)]
+struct Dir<'a> {
+    parent: usize,
+    name: &'a str,
+    content: Vec<usize>,
+}
+
+impl Dir<'_> {
+    fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    fn collect_sizes(&self, vfs: &VFS) -> Size {
+        let sizes = self
+            .content
+            .iter()
+            .map(|node_id| vfs.nodes[*node_id].collect_sizes(vfs))
+            .collect::<Vec<_>>();
+        let self_size = sizes
+            .iter()
+            .map(|size| match size {
+                Size::FileSize(size) => size,
+                Size::DirSize(dir_sizes) => dir_sizes.last().unwrap(),
+            })
+            .sum::<usize>();
+        let mut sizes = sizes
+            .into_iter()
+            .filter_map(|size| match size {
+                Size::FileSize(_) => None,
+                Size::DirSize(sizes) => Some(sizes),
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        sizes.push(self_size);
+        Size::DirSize(sizes)
+    }
+}
+
+#[derive(Debug)]
+enum VFSNode<'a> {
+    File(File<'a>),
+    Dir(Dir<'a>),
+}
+
+impl VFSNode<'_> {
+    fn collect_sizes(&self, vfs: &VFS) -> Size {
+        match self {
+            VFSNode::File(file) => Size::FileSize(file.size),
+            VFSNode::Dir(dir) => dir.collect_sizes(vfs),
+        }
+    }
+}
+
+/// A file or directory as listed by `ls`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub name: &'a str,
+    /// Total size of everything inside for directories.
+    pub size: usize,
+    pub is_dir: bool,
+}
+
+/// File system explored by a terminal transcript, borrowing its names.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct VFS<'a> {
+    cwd: usize,
+    nodes: Vec<VFSNode<'a>>,
+    dir_names_to_ids: HashMap<(usize, &'a str), usize>,
+}
+
+impl Default for VFS<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> VFS<'a> {
+    /// Only the root directory.
+    pub fn new() -> Self {
+        Self {
+            cwd: 0,
+            nodes: vec![VFSNode::Dir(Dir {
+                parent: 0,
+                name: "/",
+                content: Vec::new(),
+            })],
+            dir_names_to_ids: HashMap::from_iter([((0, "/"), 0)]),
+        }
+    }
+
+    /// Replays the `cd` and `ls` commands of a transcript.
+    pub fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let mut vfs = VFS::new();
+        let mut lines = s.lines().peekable();
+        while let Some(command) = Command::extract(s, &mut lines)? {
+            if let Command::Cd(Cd::Dir(dir_name)) = command {
+                if !vfs.dir_names_to_ids.contains_key(&(vfs.cwd, dir_name)) {
+                    return Err(ParseError::new(DAY, s, dir_name, "a listed directory"));
+                }
+            }
+            vfs.execute(command);
+        }
+        Ok(vfs)
+    }
+
+    fn cwd_empty(&self) -> bool {
+        match self.nodes[self.cwd] {
+            VFSNode::Dir(ref dir) => dir.is_empty(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn cwd<'b>(&'b mut self) -> &'b Dir<'a>
+    where
+        'a: 'b,
+    {
+        match self.nodes[self.cwd] {
+            VFSNode::Dir(ref dir) => dir,
+            _ => unreachable!(),
+        }
+    }
+
+    fn cwd_mut<'b>(&'b mut self) -> &'b mut Dir<'a>
+    where
+        'a: 'b,
+    {
+        match self.nodes[self.cwd] {
+            VFSNode::Dir(ref mut dir) => dir,
+            _ => unreachable!(),
+        }
+    }
+
+    fn execute(&mut self, command: Command<'a>) {
+        match command {
+            Command::Ls(ls) => {
+                tracing::trace!(cwd = self.cwd, entries = ls.len(), "ls");
+                if self.cwd_empty() {
+                    for mut node in ls {
+                        let new_id = self.nodes.len();
+
+                        match node {
+                            VFSNode::Dir(ref mut ls_dir) => {
+                                ls_dir.parent = self.cwd;
+                                self.dir_names_to_ids
+                                    .insert((self.cwd, ls_dir.name), new_id);
+                            }
+                            VFSNode::File(_) => {}
+                        }
+
+                        self.nodes.push(node);
+                        self.cwd_mut().content.push(new_id);
+                    }
+                }
+            }
+            Command::Cd(cd) => {
+                match cd {
+                    Cd::Root => self.cwd = 0,
+                    Cd::Up => self.cwd = self.cwd().parent,
+                    Cd::Dir(dir_name) => {
+                        self.cwd = self.dir_names_to_ids[&(self.cwd, dir_name)];
+                    }
+                }
+                tracing::trace!(?cd, cwd = self.cwd, "cd");
+            }
+        }
+    }
+
+    /// Directory at `path`, made of `/` separated names starting from the
+    /// root, where `..` goes up.
+    fn find(&self, path: &str) -> Option<usize> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(0, |id, name| match (name, &self.nodes[id]) {
+                ("..", VFSNode::Dir(dir)) => Some(dir.parent),
+                (name, _) => self.dir_names_to_ids.get(&(id, name)).copied(),
+            })
+    }
+
+    fn entry(&self, id: usize) -> Entry<'a> {
+        match &self.nodes[id] {
+            VFSNode::File(file) => Entry {
+                name: file.name,
+                size: file.size,
+                is_dir: false,
+            },
+            VFSNode::Dir(dir) => Entry {
+                name: dir.name,
+                size: match dir.collect_sizes(self) {
+                    Size::DirSize(sizes) => *sizes.last().unwrap(),
+                    Size::FileSize(size) => size,
+                },
+                is_dir: true,
+            },
+        }
+    }
+
+    /// The directory at `path` itself, `None` if there is no such directory.
+    pub fn dir(&self, path: &str) -> Option<Entry<'a>> {
+        self.find(path).map(|id| self.entry(id))
+    }
+
+    /// Content of the directory at `path` in the order it was listed.
+    pub fn list(&self, path: &str) -> Option<Vec<Entry<'a>>> {
+        match &self.nodes[self.find(path)?] {
+            VFSNode::Dir(dir) => Some(dir.content.iter().map(|&id| self.entry(id)).collect()),
+            VFSNode::File(_) => None,
+        }
+    }
+
+    /// Total size of every directory, the root last.
+    pub fn all_dir_sizes(&self) -> Vec<usize> {
+        if let Size::DirSize(sizes) = self.nodes[0].collect_sizes(self) {
+            sizes
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Command<'a> {
+    Ls(Vec<VFSNode<'a>>),
+    Cd(Cd<'a>),
+}
+
+#[derive(Debug)]
+enum Cd<'a> {
+    Root,
+    Up,
+    Dir(&'a str),
+}
+
+impl<'a> Command<'a> {
+    fn extract(
+        input: &'a str,
+        lines: &mut Peekable<impl Iterator<Item = &'a str>>,
+    ) -> Result<Option<Self>, ParseError> {
+        let Some(line) = lines.next() else {
+            return Ok(None);
+        };
+        let command = line
+            .strip_prefix("$ ")
+            .ok_or_else(|| ParseError::new(DAY, input, line, "a command starting with $"))?;
+        let command = match command.split_once(' ') {
+            None if command == "ls" => {
+                let mut nodes = Vec::new();
+                while let Some(line) = lines.next_if(|line| !line.starts_with('$')) {
+                    let (id, name) = line.split_once(' ').ok_or_else(|| {
+                        ParseError::new(DAY, input, line, "dir <name> or <size> <name>")
+                    })?;
+                    let node = match id {
+                        "dir" => {
+                            let dir = Dir {
+                                parent: 0,
+                                name,
+                                content: Vec::new(),
+                            };
+                            VFSNode::Dir(dir)
+                        }
+                        size => {
+                            let file = File {
+                                name,
+                                size: parse_token(DAY, input, size, "dir or a file size")?,
+                            };
+                            VFSNode::File(file)
+                        }
+                    };
+                    nodes.push(node);
+                }
+                Self::Ls(nodes)
+            }
+            Some(("cd", "/")) => Self::Cd(Cd::Root),
+            Some(("cd", "..")) => Self::Cd(Cd::Up),
+            Some(("cd", dir_name)) => Self::Cd(Cd::Dir(dir_name)),
+            _ => return Err(ParseError::new(DAY, input, command, "cd <dir> or ls")),
+        };
+        Ok(Some(command))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input<'a> = VFS<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        VFS::parse(input)
+    }
+
+    fn part_1(vfs: &Self::Input<'_>) -> Answer {
+        let sum = vfs
+            .all_dir_sizes()
+            .iter()
+            .filter(|s| 100000 >= **s)
+            .sum::<usize>();
+        sum.into()
+    }
+
+    fn part_2(vfs: &Self::Input<'_>) -> Answer {
+        let all_sizes = vfs.all_dir_sizes();
+        let total = all_sizes.last().copied().unwrap_or(0);
+        let total_free = DISK_SIZE.saturating_sub(total);
+        let need_to_free = NEEDED_SPACE.saturating_sub(total_free);
+        if need_to_free == 0 {
+            return 0.into();
+        }
+        // The root always frees enough, as it holds everything.
+        let min_to_delete = all_sizes
+            .iter()
+            .copied()
+            .filter(|&s| s >= need_to_free)
+            .min()
+            .unwrap_or(total);
+        min_to_delete.into()
+    }
+}
+
+impl Generate for Day7 {
+    /// A terminal transcript exploring `size` directories. Half of them nest
+    /// into the directory created just before, so the tree gets deep. File
+    /// sizes add up to at most 100M, from plenty of free space to a full disk.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let dirs = size.max(1);
+        let mut children = vec![Vec::new(); dirs];
+        for dir in 1..dirs {
+            let parent = match rng.chance(1, 2) {
+                true => dir - 1,
+                false => rng.index(dir),
+            };
+            children[parent].push(dir);
+        }
+        let files = (0..dirs)
+            .map(|dir| rng.range(i64::from(dir == 0)..=4) as usize)
+            .collect::<Vec<_>>();
+        let weights = (0..files.iter().sum())
+            .map(|_| rng.range(1..=1000) as usize)
+            .collect::<Vec<_>>();
+        let total = rng.range(weights.len() as i64..=100_000_000) as usize;
+        let spread = total - weights.len();
+        let weight_sum = weights.iter().sum::<usize>();
+        let mut file_sizes = weights
+            .iter()
+            .map(|&weight| 1 + weight * spread / weight_sum)
+            .collect::<Vec<_>>();
+        let rest = total - file_sizes.iter().sum::<usize>();
+        let last = rng.index(file_sizes.len());
+        file_sizes[last] += rest;
+
+        let name = |rng: &mut Rng| {
+            (0..rng.range(1..=8))
+                .map(|_| rng.lowercase())
+                .collect::<String>()
+        };
+        let mut input = "$ cd /\n".to_string();
+        let mut file_sizes = file_sizes.into_iter();
+        // Directories still to list, `None` marks going back up.
+        let mut stack = vec![Some((0, String::new()))];
+        while let Some(entry) = stack.pop() {
+            let Some((dir, dir_name)) = entry else {
+                input.push_str("$ cd ..\n");
+                continue;
+            };
+            if dir != 0 {
+                writeln!(input, "$ cd {dir_name}").unwrap();
+                stack.push(None);
+            }
+            input.push_str("$ ls\n");
+            let mut names = HashSet::new();
+            let mut unique_name = |rng: &mut Rng| loop {
+                let name = name(rng);
+                if names.insert(name.clone()) {
+                    break name;
+                }
+            };
+            let mut listing = Vec::new();
+            for &child in &children[dir] {
+                let child_name = unique_name(rng);
+                listing.push(format!("dir {child_name}"));
+                stack.push(Some((child, child_name)));
+            }
+            for _ in 0..files[dir] {
+                let mut file_name = unique_name(rng);
+                if rng.chance(1, 2) {
+                    file_name = format!("{file_name}.{}", name(rng));
+                }
+                listing.push(format!("{} {file_name}", file_sizes.next().unwrap()));
+            }
+            rng.shuffle(&mut listing);
+            for line in listing {
+                writeln!(input, "{line}").unwrap();
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vfs() {
+        let input = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
+        let vfs = VFS::parse(input).unwrap();
+        let all_sizes = vfs.all_dir_sizes();
+        let sizes = all_sizes
+            .iter()
+            .filter(|s| 100000 >= **s)
+            .collect::<Vec<_>>();
+        let sum = sizes.iter().copied().sum::<usize>();
+        assert_eq!(sum, 95437);
+
+        assert_eq!(vfs.dir("/").unwrap().size, 48381165);
+        assert_eq!(vfs.dir("a/e/..").unwrap().size, 94853);
+        let names = vfs
+            .list("/a")
+            .unwrap()
+            .iter()
+            .map(|entry| (entry.name, entry.is_dir))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![("e", true), ("f", false), ("g", false), ("h.lst", false)]
+        );
+        assert_eq!(vfs.list("b.txt"), None);
+        assert_eq!(vfs.dir("x"), None);
+        assert_eq!(Day7::part_2(&vfs), Answer::Int(24933642));
+    }
+
+    #[test]
+    fn disk_usage() {
+        let part_2 = |input| Day7::part_2(&Day7::parse(input).unwrap());
+        assert_eq!(part_2(""), Answer::Int(0));
+        assert_eq!(part_2("$ cd /\n$ ls\n100 a"), Answer::Int(0));
+        assert_eq!(
+            part_2("$ cd /\n$ ls\ndir a\n80000000 b\n$ cd a\n$ ls\n1 c"),
+            Answer::Int(80000001)
+        );
+    }
+
+    #[test]
+    fn invalid_commands() {
+        let error = VFS::parse("$ cd /\n$ ls\ndir a\n$ cd b").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 6, "b"));
+
+        let error = VFS::parse("$ cd /\n$ ls\nx.txt").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+
+        let error = VFS::parse("$ cd /\n$ rm -rf").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 3, "rm -rf")
+        );
+    }
+}
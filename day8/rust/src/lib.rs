@@ -0,0 +1,255 @@
+//! Day 8, Treetop Tree House: a grid of tree heights.
+
+use std::str::FromStr;
+
+use aoc_core::{Answer, Generate, Grid, ParseError, Pos, Rng, Solution};
+
+const DAY: u32 = 8;
+
+/// Grid of tree heights from 0 to 9.
+pub struct Forest {
+    trees: Grid<u8>,
+}
+
+impl FromStr for Forest {
+    type Err = ParseError;
+
+    /// Rows of digits, all of the same length.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(DAY, s, |_, c| {
+            c.to_digit(10).map(|h| h as u8).ok_or("a tree height digit")
+        })?;
+        Ok(Self { trees })
+    }
+}
+
+impl Forest {
+    pub fn trees(&self) -> &Grid<u8> {
+        &self.trees
+    }
+
+    fn get(&self, x: usize, y: usize) -> u8 {
+        self.trees[Pos::new(x, y)]
+    }
+
+    /// Trees that can be seen from outside of the forest.
+    pub fn visible(&self) -> Grid<bool> {
+        let (width, height) = (self.trees.width(), self.trees.height());
+        let mut visible = Grid::new(width, height, false);
+        for x in 0..width {
+            visible[Pos::new(x, 0)] = true;
+            visible[Pos::new(x, height - 1)] = true;
+        }
+        for y in 0..height {
+            visible[Pos::new(0, y)] = true;
+            visible[Pos::new(width - 1, y)] = true;
+        }
+        // top -> down
+        let mut max_horizontal_hights = vec![0; width];
+        for y in 0..height {
+            for x in 0..width {
+                if max_horizontal_hights[x] < self.get(x, y) {
+                    visible[Pos::new(x, y)] = true;
+                    max_horizontal_hights[x] = self.get(x, y);
+                }
+            }
+        }
+        // down -> up
+        let mut max_horizontal_hights = vec![0; width];
+        for y in (0..height).rev() {
+            for x in 0..width {
+                if max_horizontal_hights[x] < self.get(x, y) {
+                    visible[Pos::new(x, y)] = true;
+                    max_horizontal_hights[x] = self.get(x, y);
+                }
+            }
+        }
+        // left -> right
+        let mut max_vertical_hights = vec![0; height];
+        for x in 0..width {
+            for y in 0..height {
+                if max_vertical_hights[y] < self.get(x, y) {
+                    visible[Pos::new(x, y)] = true;
+                    max_vertical_hights[y] = self.get(x, y);
+                }
+            }
+        }
+        // right -> left
+        let mut max_vertical_hights = vec![0; height];
+        for x in (0..width).rev() {
+            for y in 0..height {
+                if max_vertical_hights[y] < self.get(x, y) {
+                    visible[Pos::new(x, y)] = true;
+                    max_vertical_hights[y] = self.get(x, y);
+                }
+            }
+        }
+        visible
+    }
+
+    /// Number of trees visible from outside of the forest.
+    pub fn calc_visible(&self) -> u32 {
+        self.visible().cells().iter().filter(|&&v| v).count() as u32
+    }
+
+    /// Highest scenic score of any tree.
+    pub fn calc_scenic_score(&self) -> u32 {
+        self.trees
+            .positions()
+            .map(|pos| self.scenic_score_of_tree(pos.x, pos.y))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Product of how far one can see from the tree at `pos` in every
+    /// direction, `None` outside of the forest.
+    pub fn scenic_score(&self, pos: Pos<usize>) -> Option<u32> {
+        self.trees
+            .contains(pos)
+            .then(|| self.scenic_score_of_tree(pos.x, pos.y))
+    }
+
+    fn scenic_score_of_tree(&self, x: usize, y: usize) -> u32 {
+        let mut max_vis_up = 0;
+        for up_y in (0..y).rev() {
+            max_vis_up += 1;
+            if self.get(x, y) <= self.get(x, up_y) {
+                break;
+            }
+        }
+        let mut max_vis_down = 0;
+        for down_y in (y + 1)..self.trees.height() {
+            max_vis_down += 1;
+            if self.get(x, y) <= self.get(x, down_y) {
+                break;
+            }
+        }
+        let mut max_vis_left = 0;
+        for left_x in (0..x).rev() {
+            max_vis_left += 1;
+            if self.get(x, y) <= self.get(left_x, y) {
+                break;
+            }
+        }
+        let mut max_vis_right = 0;
+        for right_x in (x + 1)..self.trees.width() {
+            max_vis_right += 1;
+            if self.get(x, y) <= self.get(right_x, y) {
+                break;
+            }
+        }
+
+        max_vis_up * max_vis_down * max_vis_left * max_vis_right
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input<'a> = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(forest: &Self::Input<'_>) -> Answer {
+        forest.calc_visible().into()
+    }
+
+    fn part_2(forest: &Self::Input<'_>) -> Answer {
+        forest.calc_scenic_score().into()
+    }
+}
+
+impl Generate for Day8 {
+    /// A square forest `size` trees wide.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut trees = Grid::new(size, size, 0);
+        for pos in trees.positions() {
+            trees[pos] = rng.below(10);
+        }
+        format!("{trees}\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check;
+
+    use super::*;
+
+    /// Tree heights cut into rows of `extra + 1` trees, dropping a partial
+    /// last row, so any shrunk value is still a valid forest.
+    type Trees = (usize, Vec<u8>);
+
+    fn trees(rng: &mut Rng, size: usize) -> Trees {
+        let width = rng.below(8) as usize;
+        let cells = (0..size * 2).map(|_| rng.below(10) as u8).collect();
+        (width, cells)
+    }
+
+    fn rows((extra, cells): &Trees) -> Vec<&[u8]> {
+        cells
+            .chunks(extra + 1)
+            .filter(|row| row.len() == extra + 1)
+            .collect()
+    }
+
+    fn to_forest(rows: &[&[u8]]) -> Forest {
+        let text = rows
+            .iter()
+            .map(|row| row.iter().map(|h| (b'0' + h) as char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn forest() {
+        let input = "30373
+25512
+65332
+33549
+35390
+";
+        let forest = input.parse::<Forest>().unwrap();
+        let visible = forest.calc_visible();
+        assert_eq!(visible, 21);
+        assert_eq!(forest.scenic_score(Pos::new(2, 3)), Some(8));
+        assert_eq!(forest.scenic_score(Pos::new(5, 0)), None);
+    }
+
+    #[test]
+    fn visible_matches_oracle() {
+        check::differential(
+            "Forest::calc_visible",
+            trees,
+            |trees| {
+                let rows = rows(trees);
+                let mut visible = 0;
+                for (y, row) in rows.iter().enumerate() {
+                    for (x, &height) in row.iter().enumerate() {
+                        let lower = |tree: &u8| *tree < height;
+                        let column = || rows.iter().map(|row| &row[x]);
+                        if row[..x].iter().all(lower)
+                            || row[x + 1..].iter().all(lower)
+                            || column().take(y).all(lower)
+                            || column().skip(y + 1).all(lower)
+                        {
+                            visible += 1;
+                        }
+                    }
+                }
+                visible
+            },
+            |trees| match rows(trees).as_slice() {
+                [] => 0,
+                rows => to_forest(rows).calc_visible(),
+            },
+        );
+    }
+}
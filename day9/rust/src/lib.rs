@@ -0,0 +1,458 @@
+//! Day 9, Rope Bridge: knots of a rope following its head.
+
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display, Write},
+    str::FromStr,
+};
+
+use aoc_core::{
+    error::parse_token, Answer, Direction, Generate, ParseError, Rng, Simulation, Solution, Vector,
+};
+
+const DAY: u32 = 9;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: usize,
+}
+
+impl FromStr for Motion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir, steps) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(DAY, s, "a direction and a number of steps"))?;
+        let steps = parse_token::<usize>(DAY, s, steps, "a number of steps")?;
+        let direction = match dir {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::new(DAY, s, dir, "U, D, L or R")),
+        };
+        Ok(Self { direction, steps })
+    }
+}
+
+impl Display for Motion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{direction} {}", self.steps)
+    }
+}
+
+pub type Pos = aoc_core::Pos<i32>;
+
+/// Rope whose knots follow the one before them, the head first.
+pub struct LongRope {
+    knots: Vec<Pos>,
+}
+
+impl Debug for LongRope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in -9..=0 {
+            for x in 0..10 {
+                if let Some(knot) = self.knots.iter().position(|knot| knot == &Pos { x, y }) {
+                    let _ = write!(f, " {knot} ");
+                } else {
+                    let _ = write!(f, " . ");
+                }
+            }
+            let _ = writeln!(f);
+        }
+        Ok(())
+    }
+}
+
+impl LongRope {
+    /// All knots start at the origin. `None` for a rope without knots.
+    pub fn new(knots: usize) -> Option<Self> {
+        (knots != 0).then(|| Self {
+            knots: vec![Default::default(); knots],
+        })
+    }
+
+    pub fn knots(&self) -> &[Pos] {
+        &self.knots
+    }
+
+    fn head_mut(&mut self) -> &mut Pos {
+        &mut self.knots[0]
+    }
+
+    pub fn tail_pos(&self) -> Pos {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Moves the head one step and the other knots after it.
+    pub fn step(&mut self, direction: Direction) {
+        *self.head_mut() += direction.into();
+        self.move_knots();
+        tracing::trace!(?direction, head = ?self.knots[0], tail = ?self.tail_pos(), "step");
+    }
+
+    fn move_knots(&mut self) {
+        for head in 0..(self.knots.len() - 1) {
+            let Vector {
+                x: x_diff,
+                y: y_diff,
+            } = self.knots[head] - self.knots[head + 1];
+            // #..
+            // ...
+            // ...
+            if x_diff >= 2 && y_diff >= 2 {
+                self.knots[head + 1].y += 1;
+                self.knots[head + 1].x += 1;
+            }
+            // ...
+            // #..
+            // ...
+            else if x_diff >= 2 && y_diff < 2 && y_diff > -2 {
+                self.knots[head + 1] = self.knots[head];
+                self.knots[head + 1].x -= 1;
+            }
+            // ...
+            // ...
+            // #..
+            else if x_diff >= 2 && y_diff <= -2 {
+                self.knots[head + 1].y -= 1;
+                self.knots[head + 1].x += 1;
+            }
+            // ..#
+            // ...
+            // ...
+            else if x_diff <= -2 && y_diff >= 2 {
+                self.knots[head + 1].y += 1;
+                self.knots[head + 1].x -= 1;
+            }
+            // ...
+            // ..#
+            // ...
+            else if x_diff <= -2 && y_diff < 2 && y_diff > -2 {
+                self.knots[head + 1] = self.knots[head];
+                self.knots[head + 1].x += 1;
+            }
+            // ...
+            // ...
+            // ..#
+            else if x_diff <= -2 && y_diff <= -2 {
+                self.knots[head + 1].y -= 1;
+                self.knots[head + 1].x -= 1;
+            }
+            // .#.
+            // ...
+            // ...
+            else if y_diff >= 2 && x_diff < 2 && x_diff > -2 {
+                self.knots[head + 1] = self.knots[head];
+                self.knots[head + 1].y -= 1;
+            }
+            // ...
+            // ...
+            // .#.
+            else if y_diff <= -2 && x_diff < 2 && x_diff > -2 {
+                self.knots[head + 1] = self.knots[head];
+                self.knots[head + 1].y += 1;
+            }
+        }
+    }
+}
+
+/// Every position the tail of a rope with `knots` knots passes through,
+/// `None` for a rope without knots.
+pub fn tail_visited(motions: &[Motion], knots: usize) -> Option<HashSet<Pos>> {
+    let mut long_rope = LongRope::new(knots)?;
+    let mut set = HashSet::new();
+    set.insert(Pos::default());
+    for motion in motions {
+        for _ in 0..motion.steps {
+            long_rope.step(motion.direction);
+            set.insert(long_rope.tail_pos());
+        }
+    }
+    Some(set)
+}
+
+/// Moves the head of a rope one step at a time, drawing the area around it.
+pub struct Rope {
+    rope: LongRope,
+    motions: Vec<Motion>,
+    motion: usize,
+    steps: usize,
+    visited: HashSet<Pos>,
+}
+
+impl Rope {
+    const WIDTH: i32 = 41;
+    const HEIGHT: i32 = 21;
+
+    /// `None` for a rope without knots.
+    pub fn new(motions: Vec<Motion>, knots: usize) -> Option<Self> {
+        Some(Self {
+            rope: LongRope::new(knots)?,
+            motions,
+            motion: 0,
+            steps: 0,
+            visited: HashSet::from([Pos::default()]),
+        })
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) -> bool {
+        while self
+            .motions
+            .get(self.motion)
+            .is_some_and(|motion| motion.steps == self.steps)
+        {
+            self.motion += 1;
+            self.steps = 0;
+        }
+        let Some(motion) = self.motions.get(self.motion) else {
+            return false;
+        };
+        self.rope.step(motion.direction);
+        self.steps += 1;
+        self.visited.insert(self.rope.tail_pos());
+        true
+    }
+}
+
+/// Knots are drawn as H, 1, 2.. over the cells the tail visited.
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.motions.get(self.motion) {
+            Some(motion) => write!(f, "{motion} ({}/{})", self.motion + 1, self.motions.len())?,
+            None => write!(f, "done")?,
+        }
+        writeln!(f, ", tail visited {}", self.visited.len())?;
+        let head = self.rope.knots[0];
+        let (left, top) = (head.x - Self::WIDTH / 2, head.y - Self::HEIGHT / 2);
+        for y in top..top + Self::HEIGHT {
+            if y != top {
+                writeln!(f)?;
+            }
+            for x in left..left + Self::WIDTH {
+                let pos = Pos { x, y };
+                let cell = match self.rope.knots.iter().position(|knot| *knot == pos) {
+                    Some(0) => 'H',
+                    Some(knot) => char::from_digit(knot as u32 % 10, 10).unwrap_or('?'),
+                    None if pos == Pos::default() => 's',
+                    None if self.visited.contains(&pos) => '#',
+                    None => '.',
+                };
+                f.write_char(cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = DAY;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input<'a> = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut motions = Vec::new();
+        for line in input.lines() {
+            motions.push(
+                line.parse()
+                    .map_err(|e: ParseError| e.within(input, line))?,
+            );
+        }
+        Ok(motions)
+    }
+
+    fn part_1(motions: &Self::Input<'_>) -> Answer {
+        tail_visited(motions, 2)
+            .map_or(0, |visited| visited.len())
+            .into()
+    }
+
+    fn part_2(motions: &Self::Input<'_>) -> Answer {
+        tail_visited(motions, 10)
+            .map_or(0, |visited| visited.len())
+            .into()
+    }
+}
+
+impl Generate for Day9 {
+    /// `size` motions of 1 to 20 steps each.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let direction = rng.choose(&['U', 'D', 'L', 'R']);
+            writeln!(input, "{direction} {}", rng.range(1..=20)).unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check;
+
+    use super::*;
+
+    /// Single steps and the number of knots beyond the two a rope needs.
+    fn steps(rng: &mut Rng, size: usize) -> (Vec<u8>, usize) {
+        let steps = (0..size * 4).map(|_| rng.below(4) as u8).collect();
+        (steps, rng.below(9) as usize)
+    }
+
+    fn direction(step: u8) -> Direction {
+        Direction::ALL[step as usize % 4]
+    }
+
+    #[test]
+    fn rope() {
+        let input = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let motions = Day9::parse(input).unwrap();
+        let mut long_rope = LongRope::new(2).unwrap();
+        let mut set = HashSet::new();
+        set.insert(Pos::default());
+        for motion in motions {
+            for _ in 0..motion.steps {
+                long_rope.step(motion.direction);
+                set.insert(long_rope.tail_pos());
+            }
+        }
+        assert_eq!(set.len(), 13);
+    }
+
+    #[test]
+    fn long_rope() {
+        let input = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let motions = Day9::parse(input).unwrap();
+        let mut long_rope = LongRope::new(10).unwrap();
+        let mut set = HashSet::new();
+        set.insert(Pos::default());
+        for motion in motions {
+            for _ in 0..motion.steps {
+                long_rope.step(motion.direction);
+                set.insert(long_rope.tail_pos());
+            }
+        }
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn long_rope_2() {
+        let input = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+        let motions = Day9::parse(input).unwrap();
+        let mut long_rope = LongRope::new(10).unwrap();
+        let mut set = HashSet::new();
+        set.insert(Pos::default());
+        for motion in motions {
+            for _ in 0..motion.steps {
+                long_rope.step(motion.direction);
+                set.insert(long_rope.tail_pos());
+            }
+        }
+        assert_eq!(set.len(), 36);
+    }
+
+    #[test]
+    fn simulation() {
+        let motions = Day9::parse("R 4\nU 0\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+        let mut rope = Rope::new(motions, 2).unwrap();
+        let mut steps = 0;
+        while rope.step() {
+            steps += 1;
+        }
+        assert_eq!(steps, 24);
+        assert_eq!(rope.visited.len(), 13);
+        let frame = rope.to_string();
+        assert!(frame.starts_with("done, tail visited 13\n"));
+        assert_eq!(
+            frame.lines().nth(11),
+            Some("...................1H##..................")
+        );
+    }
+
+    #[test]
+    fn move_knots_matches_oracle() {
+        check::differential(
+            "LongRope::move_knots",
+            steps,
+            |(steps, extra)| {
+                let mut knots = vec![Pos::default(); extra + 2];
+                let mut history = Vec::new();
+                for &step in steps {
+                    match direction(step) {
+                        Direction::Up => knots[0].y -= 1,
+                        Direction::Down => knots[0].y += 1,
+                        Direction::Left => knots[0].x -= 1,
+                        Direction::Right => knots[0].x += 1,
+                    }
+                    for i in 1..knots.len() {
+                        let (dx, dy) = (knots[i - 1].x - knots[i].x, knots[i - 1].y - knots[i].y);
+                        if dx.abs() > 1 || dy.abs() > 1 {
+                            knots[i].x += dx.signum();
+                            knots[i].y += dy.signum();
+                        }
+                    }
+                    history.push(knots.clone());
+                }
+                history
+            },
+            |(steps, extra)| {
+                let mut rope = LongRope::new(extra + 2).unwrap();
+                steps
+                    .iter()
+                    .map(|&step| {
+                        rope.step(direction(step));
+                        rope.knots.clone()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+    }
+
+    #[test]
+    fn no_knots() {
+        assert!(LongRope::new(0).is_none());
+        assert!(Rope::new(Vec::new(), 0).is_none());
+        assert_eq!(tail_visited(&[], 0), None);
+        assert_eq!(tail_visited(&[], 1).map(|visited| visited.len()), Some(1));
+    }
+
+    #[test]
+    fn invalid_motions() {
+        assert_eq!(Motion::from_str("U 1000001").unwrap().steps, 1000001);
+        assert!(Motion::from_str("U 99999999999999999999999").is_err());
+        assert!(Motion::from_str("X 1").is_err());
+    }
+}
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Steps are simulated one by one, so huge counts only make runs time out.
+const MAX_STEPS: usize = 10_000;
+
+fuzz_target!(|input: &str| {
+    if let Ok(motion) = input.parse::<day9::Motion>() {
+        if motion.steps <= MAX_STEPS {
+            let _ = day9::tail_visited(&[motion], 10);
+        }
+    }
+});